@@ -0,0 +1,439 @@
+"""Copies of the core images in assets/, so the display still has its slider artwork when
+the files are missing (e.g. only weather_display.py was deployed, or ASSETS_DIR is wrong).
+
+Files on disk (ICON_SET, then ASSETS_DIR) always win. After changing the artwork run
+`python3 embedded_assets.py` to rewrite this file from assets/.
+"""
+
+import base64
+import os
+
+NAMES = ('bar_full.png', 'bar_indicator.png')
+
+EMBEDDED_ASSETS = {
+    'bar_full.png': (
+        'iVBORw0KGgoAAAANSUhEUgAAA4EAAAAiCAYAAADhytwRAAAACXBIWXMAAAsTAAALEwEAmpwYAAAAAXNSR0IArs4c'
+        '6QAAAARnQU1BAACxjwv8YQUAAGGJSURBVHgBjb1bous6DiyGciaVj8x/VjFyloh6gPLuG3efvWxZIvGqAkDKNvr/'
+        '/n++heID8e8c6f7vn/+O/PcX6x2fd/5e789lf399fs/Z+9znyN/4/10An6Lz8n0e/zuJ42MGQc6dgqRmHKf2+X/j'
+        'fz4IeSkjr4d14jUh336Psqa+teyI0FGShI4IAbFsuP9W6tnlsUui/74m7GA5j4h42aBe9vo7iTa55+h7zl8xhJDX'
+        'g+ovlj3GB2lTyX/GbMXb1uvv+eNbRd/IwHNXzHruM1W8rtp2poOueOp2vPzb3rRzy0pU7V92/HvxKdvREb3OGtPy'
+        'vNFXtj+K7Xn6jnKP8/LBb/yvOasW5vP1R4j5LXMX1rh7zK3Tm41S5nzdYUjseRIn4Z9u2xXVOZJioW855k0sTLfm'
+        'rIiXv4nt1/DlvNeJKTtnxQJSec7Z5Rhb7/3g4tu2YXu9ClmNr22NjKVK22DPdfudsd8F4faZq9OmPkYl5L98T3lh'
+        '5JrX4ue/ayJ2KudMfrtj435ftqKdfsRy31FcP+ydNqxK/L+uvGM5dP+JBR5L/FeSCxaxmIPqrSv1DfzjOnzGiDmX'
+        'fvVDX0hmYYYD/k+bhc6MEbwlNzZjcl3KWAoP0TSXfyjj4SzbbefWkHnhw3pygjX/stPzZgtNwDfoques85/p6aGJ'
+        'OeZrn9foMHY/of+J66pm/L+EgnPNf2f1XPffuN8u+uWpupqUNH/n7eeabyTV1vNzwte+6W8b5B0O368frunv9/i2'
+        'T+J+Zj1jPfL+N9ax7d/8kpHWfNT4k2tU/tNr8P8NuTX+PD82+f439gcSWmOP3UeF/64ZO0Jy/F3+dTS9/CA/l+wf'
+        'NniKgbGXSaHT/8tn6a9jD8kYOmpeAK3A/O/6oLk+fPnIRNTkvOXk9Z8PgFryRAwG/r9zUiN0Oa/H/wcsquTOfLY1'
+        'eD7P+xv/9uEEaAUuCo5vvgcT9onlI2fTn2OY75+ZBg/f9NeEtTG6MLnkiPfo68fQR4ajqHSqW+9S7kr5Zc+Fq7/T'
+        'PkoX0lMAcCxwDAifevyFxXPiZ83WndfNUxIvOMpld5SK5Se6NG1z+kMoZFwd7OD3XeyTxVmsHzGccYCrWFUiqWWZ'
+        'JQCviHmiBi9EIbEHiGsvO61CX3aocnZEsVli0kNhj49oZmG7dBmWIeZbvFHkNH/HR5nXf14TZj4iHBk+Mxm97KK4'
+        'f8zJCOhll++Kn1+SWwY2aHkOlsYzN1Au9I6zQbIemIpzI0urORMb+d8mnVzyrJisH42G/LQLrzT656q47zE0B9yC'
+        'uanwkGGEOH8P2uUiiaOV9C5lbp3bxh0LnSHjw/eLWuJ6xuZDZe3zZ+4eXJHZkfrLfcHWDG5eb5RI+8bKRrJM4x1P'
+        'MdR62CqeW/PkuZyrq7b7Av8mynJB69OUL0VIw72PDZ0p1tQZc4F/1KXIpWyvQBlZ0gc+TJHTKPvYyCuIhe/zxByX'
+        '+iw/88J0RtPmbx2m6Aulzt/EgRo4ZOwEN43OU8xEbHhWHnN8UbSN/0YuoIXx8tDYy80rbRZRmgF0Kd7SPWKTPgue'
+        '3am4l+57vFgwu2zAnMs5F+663gsvbYs4rKC/u8Yp8YAw2c5ZK34D/12W421r2tD4X4PwPeEr34mhqi7ewJt7Kuxs'
+        'YxkzyZkxQy/nhO0wxxXXae+Df+qUUOS5GIOAWvdmsh9U5Zci3Y1/ua9XZTVXnXKNAw59dIQci1MedXmY7y1DSbt9'
+        'XdqpPCMW0SoCWWme9NsMNvQchjDWOv8gEukNZvBi0f+cxzCYrmTp9V8D+InciMBbX8ZrVhc9Uv335GPlcHtZlpMC'
+        'I7Cb47Flhqb4Vvjvi1aeDu+hraed7eND45/NC+iH7sD/NCCtlLMQsfzC6Ahb2549tvprTlmCbP1X/i96ReQx+YtF'
+        'ZeCf0dTyOvNBDA3B99sqqDgSqjf+W/zSoSSQoHb62fg/khr/oCiQHtTPlX9v/J+rBQkg8n+PXTzWLwpErVQxcxwd'
+        'Dgd3bcq4GsDRZZZZEjhnJyyuA/Kvo/dFinNe2RmlHnwKyxZagjo7xz3HTsKCCbW3FohARgyTxzJGsMKqBme4TfQ/'
+        'Hl0JU8rJ4i1zyE5q1H1Op19gbYxx25rQvGXwNT+EjWRJ/Xfj9gPX8A6Gxe11isQPsl7+rolIiYFnx41wi2C1mFAG'
+        'KFL6ckXfJSNyOpnjwtspSiPGlOVYmCDjtxa4tm+PjYOhPOnoyLjuKzZTB9NgO6ZzPoXDNblOyBiOnYItjh/IEjfH'
+        'ul+ikiDnYjd6wvrgqTf+mQae65VMSg2hC+NBuTKshb/xT+CuJNGvFDx/ce1erJMqJrA819t38X9ftuOBOT65BEpE'
+        'VxgG5+3B/xYZP9K/ZZLWhBELvdMlQuLE6KuADXzVTvaF2uZWUoxYXIkHcRzZoFfwWe24nrMk84bxxcnREHTy87GB'
+        '04h3IrcP6z3vKWkUJ8w9SdId+Jeay98jmZxt30Dkel7LXLR16MACRjuewBa+f8xVMV5VYFGB9zNu+9bten8/tr8Y'
+        'C8/pH1w2OLqIFi78K95HZiz1oNjG8PDVu2w5rr/4H/jnmQva/QYy6j5pz5ePvl/A2Mtjttf2CUPjMVvvTbFHDFj/'
+        'aXJC2sfWcFPeWhNnsb0KZFLBedmlXR+5SRxyhngtm3Xin+adES/UPuNzfiqAX/g/GoDPcJclvPaZCWjlHOui4g63'
+        'nCXTneOG3ERYJuZpDt/47ynGkTUipYLY7vnz9fM/ZEhfBkSgtLFSinuhHyRdi/s312iAc9SRIdvn5CfvqqFlQ+dp'
+        '6C1r2dKStj3mOGb5CDEQ/qe9oNH6RmZnjvrvlA/ZDZIYUyxFc59iQfXEg3u6o7O8tmazsxsW7fbOh1ZUGG0/839v'
+        'ZrkeyeYtk7VS69+WaHHFRjYeI3QQlfFu/Ou0LjdygxVljO7oiGjbZ9i+ap8JgXAxdQ8l8VNNvf2ZCJpwfBXgJc1G'
+        'XD9BCmnLtxnFSTPwn57dEVuyciLAc/C01nvHLO3z5+RM6gLLvFjN2j8fbdxppynGG5LV84jBbTrapMM+vfng26pV'
+        'bNTUpacJwP8PuX0t7gpM/2ZSsz06jHbUuwNnGGnnm5xyhu1r3k681+vtO0CxdTmudpPcMVOFnEDvJk8xkcINP1UG'
+        'dEt/5sGMJYQsmhXjwyEzhG+jfC0GNa65PfyOBfnuMzklCyc+f1IOF0t+AzyvqffwtUKNwYcLONICsUjg8XUm5WGB'
+        'aOKPSeHIQngg0ugrDJx2azFi3TuBjkfRTarRlcR4q3fpes79yG8PPxcyhun6wL+LOlz53M2JUsBlY45zyMyKyked'
+        'CvhK8VjYrnOMK66CgXxYHE0dNEfXrrJ0paowxOJMTlX7fhEkd/4gMGDH0oqPyOA3/lUITEnyjAOWInH9qkQD/121'
+        '8MiM3FtOJHfxkrEVJvZlxKv58+3PpcWQbBiJ/74nwyxMt5L/2FEltkSKduTS0zrdw9/4z4989I8cxoLVubSvMcEi'
+        'VPNHO1Ad+F+5IvFfFzRX/EZ8RMDRzrIZz4kUJ7wOZknbF90U1lxqxv7u+fuJ/0/1dX6Vi2qeasznQsVjBw55OoZT'
+        'TgTRMe0/b39w20NUd9FCK54Qb0zDhVJxP9e3b5apZtuW1jvPvPP3idYe2Kfp5Dk82dA7PqusJ8WmtE4VrOqE5vkb'
+        '2aqp2fl7cnIvH2A1LCCeMDY/EplHupYfsoTiuDE3aO+/Q00TOlJnbGn2zLvRztG6Xtp8jgUhnvvbp0yGrjFQvTAe'
+        'ERAkd1MZQoanyIX3BPeIgqkjvpNUodz/d+65GRTpvqh48uavc1trVehCS4koXnHxZ5O47Vz31wj/VUliLJ/HGCNT'
+        'lBa3sn//8GM3f6d9B7Pw3sazV/Y5wrN5mzm9thCsfWQ2FyRJaOducjfvZ2uZQvoiW6Jfss+FziGeJN3V+fzs2xz7'
+        '4XUKJuJZs3jpYWG3ev7nBosAcfEIXlPlmmbe+KWJG/s41peMGKsBOl/yVq3rWeDzXMlEylP0lDG8ZA0jwicmZXqu'
+        'WmGwQ2OeDzYx711TrPPyc0psULpaOpm5SBaRXKt20p0zmEG6IrlTRgyK+JJy17ZFhMzLVxXz7MnPjLxtuJd+4qp6'
+        '2VP4W/uOz78fNkzlpYic0gURhcZ6N7in6JNiHOXc3WKXvfupE2O2ado16paZZ3YmgNH174xzg751bsrOjP2knZ4Q'
+        'eyOIxXcWZZrgKixz3Pv2q8RvrQIqbneaIpIFFgm0L93VFgoTVXfCwcWNt06US7LP60jgtme4faoYVlVrrt6TeK6J'
+        '67/P0ywbo9Rs3finDOdyv7HsCjdTB4MIXjUmCdBX4zQ6JIZoXUVzZm04rnjmy/QxzysSUJd8tzz1gm2el34hqMRd'
+        'lZhux+wt2w/8qwk91b3lunxiRNZCLwLNee4Mt2OhQokxHmTXjX9xxoUBS2HFhO3ecXhzXld6uNIwL38In/j/h//H'
+        '05+QO60RlJx3BGwsUoGG4x7rtkfGuUKg/IbjMg53L17MOfu6oonpruDs/SBW5LRam54OmU77zWtsGys/3HGquWrh'
+        'H7ee6rqmgl24k2Ktz+tVFC0VOqiPSUfxjN601BXr5n8Nxi4rjsqS4zSHaRwY/r2Ky6pVOrZpSzmlAu6OlSGCSmYy'
+        'U8VaQcsr03gigE4smuFy5ycaNroRxcQ8sd/tySB3tHir1xLWFKHh+8dWjJ7ARdePHbO06jTdFV2QyqE2rs84R4X2'
+        'MenVF/4hO5XvzPTu0Xw87XgE5lsoi8hTx2KnC6qlDT9veGzj260ez3zAJmdaI0olieWj44yAkQzNhZGl1IgxMjLc'
+        'Bv/ey7I9ZftuNmwo7T+PiMt6xv+Zkoa03YMWkTdomGG7dJqoM/BvnE3+j+YIoTKXEHQnjOR5dkJbBelETt49MnF6'
+        'pl8KSu4suz8zQa/T3DQVp7eIPejtTG1DHTx1rt1GEpZ34SvjlCjJt+JJolW0OIY4Vzyv/TfUmiBEyGTytxtzTugm'
+        'Y57TKzJpO0hxN0qxK9B5PV/Adv6kvl2MwBLfbz0Oi2H5jrB3hI9YX6KaOUXsK+HwMtZcjHo101Zog85roXeS2Kxc'
+        'sOxCGs9LP9TWuUsmj9ErSXSd74bFb3c4T3v5TVtTonv865rqyib74A0r3ilX+jMXWv4ez70mncU7y6SJHslUxgYf'
+        'fxw8H+BcBV7KTZl6Cr2ZIYuau0jUWd0haYvOWn7oFZjagbmIeFuUTWT7vHyMjjlzGhPb+ZXJb+3c1A/sexhH6DW/'
+        'PzkxOtL+X7cKEIlUBNfs9iygYOL8lsUNsz/LVhUFU7kQMR/dtuLuk+xJeyT5joxYmO3zMfAL//VWqfrKcK0XMadF'
+        'VCwHO0uWlPPGWX6O8jxt75LYbLWjuwpinNr+DK6wcNSL/Af5zMd3k6VC+o7xcYj5KK67w7Zuve9Ybemvu6h4ave2'
+        '0474muL3hX/X27vZlZzP2JAdENcumSQy9X3jvyPeEv8JkWWHjIN4z+fa1rVyZ0dw1jV63Z8R0rs3DxD/Gjfli3zL'
+        'E4jRtE3q9wv/x3XGZ1024J0XTUc8bxhrqkubS+tPCbIBMLe9iJPmyihOfNAxfhqW57SOG2g6+11HE+IOta7Egst5'
+        '22Ju/5yqaZWeYLLVmeVbdCB9ebGTAPW1VijvtZ03lCWb53WxC1rg6WVT4/858O1mQ3g0zDhV4dQHL50x0clWV4r/'
+        'if+xh+wYO5nQ4kziH8ahmyfh/7Ef8zw9V8papwFszYQxMMYj2Dliy1TP6dPvdWrS+qwXzzqsnet7CE3PECBDCAKm'
+        'K3ruGJoKO+6AJA5UXfnfoSvrVp5OZ8mKFN/RFhFeTyN6VtK+rRVv1GpOR/7Bv2wxZix/vnS07ImdrvN503ZB4u1v'
+        'YhQtjO1y89hHRTQX52K8asTXa345zohwSCzG7LxhRQK7KWkiNegBo6txXLkLtRqfqkpAswkIKh75XCinK5D/oMr7'
+        'yB5wEWS12L8rWh3IJCVr63Set9sXI3XiXXatPa4GwpzjRzaQLhtQtWLW4vD5aixB0XvPTTmj+wCvUazllDKMBZ9k'
+        'JZKVTbDmELLhOVmw8VSWHN8Ou6eokoFe57MLrcuE0aCGLfI6NjWd46IjJrGGT8EYr2tJp0hFOz9filgmX+GMVBmU'
+        'WAUHffwsvkdB7FsqzgkuQKJp0PXnPcx1MfForIRQq9i1RGWy70MglPpSmrjEYmus2+ByFeqikqU7DYrKNrTrboau'
+        'IIjCeR/Hr9eSoevKG0sQeoh2IFJ5rJF+4TX4OS/95TQZykf8ni9TgXcvLM71fCUWSda3QTsAXrWL1shWyFj71rJ+'
+        'FqCmBceaojI5Vthrj88zM8OHKDaMH2nntIHW1pNfOrhg+eL5mr7XuBrhh34Vflpx17V87fxTWzjxaMd8niNjms0R'
+        'UgS9RnmlN8ZVPDbPql6C2M8/8b94CT9wEIy5ri1htCrMhCsWGTe83U74rTdf/sB/BT9lI3zjX4eu44nd12TYHNz3'
+        'KZdQF/51zI09Xsdue2L5qzb+NaXxHzboO76YV/+i/TMrjBiHd2/Zxwpsqop1ao1nxkgY/PdJjC/8byaa90UFZR6d'
+        'ZoOgRJLCY0Vu5nGXwmAaM74y7d/nq5iEzoGuCuA825ayZYctjdz+OirF3yVrIV1luwVcEE3HY8QcH+valv9alkMQ'
+        'RyZoIuMuW70fMjeIIhJXjxXUmo9RQw9ibtYkzixf9jbTHB5RYjPLEkw20K5gtQFDiwhkp0SMMY7xhowZU7yBtvAD'
+        'qiUPjvFQnRjtafLA+Fx5NHzoRvf8u/MSc9W8171zrB/rorntfkSTfSo0OhrnnRX0e8jxDfzP6N+nAYvIGbZ79AxM'
+        '6UaFcdjZCTVvnw/ksp61TvpCGMk+32Yqa5/x53/AD/wDB2HYtokAgHPSq0Hr+HvidRpRLhKgVthUefenwm2Ur5mP'
+        '5ngYU2PkDhKtQxlrwv3Cd62B9I2lyEFrP7oqr+v4vOy8hTFmrVgYtyy7QPOuWaJ46ytQUVi2d2gdgeAMU0w8vt2l'
+        'lDKwEnvvURahViVHCJz0X2OPVVhhco/t2yB7m9a8HD7afo77iXSkRtOqWsWkPPgVmEIMWM+L35Z/lbPiMIu37iXj'
+        'K0x4ML4k7dc52tGtjZfVnKFUCDG1jQXLfEhWZlJmM1FPgLpIexcwKoRRa1ewOUdNHupS09NWa/IvQo7SKmqZv3Re'
+        'XyaiZOkJa4VhwvofzcKPZ6ig/NLO3fZ0xIlOnOO0uai2bAf8mt+ATL/cxbkTU1d4ajBZsp2L/m2RxsZ/THXuEGMT'
+        'nYVnjoHt+zPo5ZXAP4vOFMVtL/35A/86YRH3sSBlNBFz4hUA8k/HtbX9jZCnLt6QbmPKesVPxOrzPhTYqkbtLuOw'
+        'llAUTfPoDMDW2IJ7hJuDyAe4mzMZX/F5NybCP2M98F+J/8mLwn+MI7xVvNeB3wv/ttG2KQ9aB+OffgpcXTFEpca7'
+        'LDEv/71rOOf/hW8KDFwEgwUDxyTE9R3+lR2u+Eg7eaQ4LpGJxxgb5Rjgf4qZl8BHglAlzvh85ksuoRsJ591m51cu'
+        'OhC6d7twBWcYKVvRoDNgRLQaVw51YVTFtvH/yIXq5azBQvel64oKFw8mpYn0SlpDXDd5epV+0HXB82d3jJwvd1Hv'
+        'E0xY+W30r6paEOLM8HL4epwgojPVYxpYu1mjrLYr7wrFen8aQgzGmwtIf+9+WPNH3E3e/2aMntgGq5te+T8jhUb6'
+        'r7/5TpunXKEQqjh4nPv1jzJo8B7AqfSQIXGEAVjCopL3TInnpXjtuFyv+3yoUbEJn78fXMFjlb0WE+S6WtF15uuC'
+        '6WyxHhCB197BawFQ+V9anUWe5u5doEexGwSXeVm5lVOo8RqbyM4RdR2MTqKnfl1cZhk5cidryddh3FHsE+E6iSX+'
+        'lIvdxe5XA9LlZZKb8adwkU1LzQ7ZK1is2FJX1WXXCgfcQcGjsAzwc8r+vcbpWauOUm5s2+X7EFD0z4r8or867PcS'
+        'SYggxvA6CcG7uGs+22Xp6fMj7NakWRTlWz65M2In2WBpJxlSazJAv4tDrCtfyC3udm4dL4aQeBm32JVIXCDKhRN0'
+        'AvxH+3CNwOfYB3ohSnjAj+Jcqz19FxkVTRjsCJ5HhhgM8DgTSSSbUdEDI6qtSJCTHNK3tmNG72owx2C+Ve5Fn3da'
+        'uXbA2udd+CfR8b3u65xvK209pmHa7FpFq3xBZwzE24RWpvDWOZQNtdZ7lmxIO9ZMbLv827fpK3F1ShITttkjC1KT'
+        'fb0gs+xS9WKPCvyr2Yh5KqGFiubnRywMNnUNnPnrFTeo28+aCNZ5RJcoc9PThbPf+Le/UMb6yDe2on1WzJMC75gI'
+        'mySuU17P/UMtk+5GCDGrPIZ1YfraB8MW/Rv/JaN1+LFj5sRcxNbYQsev/M/Hjf+OAEHIX1lmpq+u/E9Z8Av//iJG'
+        'xaewmPwibGfuUdts+pxCVirB8aHaTRVkz+5zp1DF4F78hLmdN/DPYk08EzTHj+ycP9hqQwUgC6j5wsUoeRBfVTHJ'
+        'JUbpsM8UMRcrR2FA/B9V/Q2gHYROM3W2D/TIjf8y7wRoegaC800lIFSCNRfYOcexX08e/GjIOQILMwP1KQG0S52N'
+        'Tuh+8E828Gekho87GkkHneRVDIUfzORBKewDdHOtCuQj13z+9e+HFBcoR+he+LfWuv1+RGNDapvNL/8dua5vijQe'
+        'EdaMID3V9MTOhzZQrrAdW8UZO4FwAqYoVuNY1Rf3KLHfu3YL/9NggqfPbpmIc3YR3bi/tl3YLWuSzsh2TEDnDDdN'
+        'wM20JVA/3f0c+bv8c4QD69SpRFyLdIU2rOzPrZ1Ygpann29NCw5K9sGKvJsAzBAlBmTkbvx/ojj1eX0nkCBYmjCL'
+        'su2+c+QDR7LnvzS9E3uMNZvGvsS5Ru9nAemPYV7jifXPqwUpzPsOh1ICXWPN+N3bDAHQJNfF+ykvIo+8dLYuyydR'
+        'aWbD8RpD8jGpIpL4yKgkuSEC2YHxBGaPegcolMe1FxF+oMBc1LtjI28JtbPr98OoYfTbV8n+7ZxHHQr9j8HOc/zD'
+        'Lq9TZ6ocruPc/Izud2KJ45bFn98ljDGjeMEPBLE41W0IvaqI0KKXVgwywaNvK7ig7gh86PraTaO9vGRfMZtzYH+7'
+        'lud5y7KakfohZ3wrOvC+FllAtldAV8y2C7v1uZ9kW9zzK3tq/JU85/Iv+VKYgIYsXOriH4rq3fAvsLhD1wOv4TZG'
+        'RsYfBJQxtIvymo+ItVExwbPg0DtGjPSqLOSZ8hWLVolEVvlIUdZx/VP7NiAOC+8CDfVpdzRpk4Nng/RRTG+71D/w'
+        'H8P4vW9ck/9JfjchNfhf+YAGDZzExQVZMPCHWnH4C/8dSeaXGioiqRF8fiX+O+QsbB8k7mqprQWRvDX5hX+sk//x'
+        'CPznNTlR8tCP/I/R5ctq6coj0nVDyzbXsbFs2IVHLWu8Jl8tu8TuE03udgewf4nkmL1KdbYcV+UAqx8xCBaS5zTe'
+        'FHrOTfZrnh23Jk/1dl4mW2i8kfkcpIStxiuCqhzApX0x3PL/eHRfT3JzFTIWMYbibXSxo1enDKE+lFtffBKNoHf/'
+        'bkEQfzuC3gQUPRM1Vi7o0+R5JBrpHNHtkTLMEYpNrtxAGyeuIMS3G8QVz32bFLwFveMur6FnqAGwdE32Disw2Y69'
+        'vBb1jPap54fXia3zzZh42VUAC6RtRGTvGuF0XkPuP47v6jeZ9Csn7GKrNYmhWyQIxGsov/rir7qQV/7HhX/Uhf+l'
+        'TaVYuYMoEaaVtz3gMvwcaAahPDd+kdua/BcmODuBLGBPbHnHT7tLapaxRE49zFZ75+tOUXl9XnfCLKwGYiwI7bq6'
+        'LejYaHnRNZfA2Drqm3B7JjvHcrcGIf+wSGWT5ERKRdxAh+ekOxnI8Eck09Gx85WMo29E6tAv44t08CfTB7kw0Blc'
+        'hfDlQkJbnu83J4hFuTeCFYk+YS9KdF6YBdLNsk3/7Fg6IpFzY9V7BcOZ03dEXQbawuhgzveJQoi1hHYI59wuyzLT'
+        'ZrieJRVSaVUUFbFjE8p/5/kqHrBtkgfdsEV8YI6/01axQGTqUBGD4qcwYqLBfS+FSmUDQsYqf17uulVmMlHtAtvv'
+        'pWy1p39/pu4RoSfu4Zwrves3/iX/lrWyGQwwmbh7Ek+VF3M0EQUi2lRYes7xc3fEBpZd8vGrgK8f+Ef9TG4OdfqW'
+        'XyCOq3nl5/aUotMmpXiQPN2bdSNWXrDF9m9XzM2/bfy/tSg1h8JjyH6y4JEhd71SHsYnv+BSMV8/5NV1Y7Ma9hQv'
+        '1saF4p783Yot2EHCS95+ZB40tyCFKOv02zIVExBrqP4H/u8REv/+TF+/6VDNQ1X9L/zbMS/8KwwuPlv26P+N/8v4'
+        'c6gjN22L3Pm/7vzPcYjTwb9yUl/YUq0b+l2Y7KjUXxYP/I9LKj/LTBOD08emQ5X7gr7wP8S0DNMrlCebf87tgfZb'
+        'QV82cux/xuEOlKTW/P5dvSreJtpWXqZzGzRnQE1WbGsoNIT/FXqR3lbrhbQI7HgEL+Xl2rGL+doyLptTeuqqMFa7'
+        'UnTAQTPYtKoI2XsrjrUh2nmWcoKbgCWMKQ0dMdv5suoiLd6OygbvRnor/+/wr15VHoadWqSgsD7aT7nd3B4F95m0'
+        'bGKtSMz+8hnCZrpHxXLUQx8jxveKBPQy/wtGurcSOwlOsLVYFrg21BR/+rxje4i1eFx6n7dCgP9XfpMDcaIEWLlO'
+        'NmYrru4UFrcDTMdGHWxEm4G1jiinvLAai0ZRvj52O2g/DUA5Rjts+ujfq948j/mJCKnhxNWlmDXrtENMj+sYOVmB'
+        'pnfNDkexLiIPIcCONaf0ixr3eZ3Heltph8Z+BOqCb6qkfa1inuPqskVrjBdLKrRFghNeA7hLJ9palyApYgnfkvOS'
+        'rap6Jav+MYIDxO9Av8+GdOR5q7jrNZhKySuTcDaGO93kfL0k8g5hyFLlAmNiI2qRS5sSJNZ8vYLjPR/R+a3Q9+iu'
+        'tdCQZes8c/ee6uTSzJp1MdgZ8VsE52AvyfmDGDDsIH2xdhOp4mrvwYUJX/NvXFYkEuQJEQImFE/b13trcOnz1bfy'
+        'RrOxCPacqCG7dS5D0VljT9JWV7eM4Bf++y0fgjwctoGN2yYcdztVuvZ9MHWOB35hEo4lhe9NMkvulp02P1tm/c7h'
+        '9cgEkXJmF7fsX3vOMjTqxr9or/paRPB8eTsTZr7zVshiYV/437oYtbkAkbc+K2mHP5vzSWZY5gr8EeuQft24MJJ/'
+        'Z9z0MDQVll+FG9RL3zVg4v/vOYz/bNBWezL4/4Z+t5z9I6fmsf6B/8/CRuD/JynXwu7f30/EUK+cH7JMEkwc/pJ1'
+        'LbCgXjH9yVe96aYD/278yqv+v2zSGbMeu9M5Zdl87MZ736efg5z2gIPgm1/6o22IzlN3f8c4a7f2+V46TGPmuYeP'
+        'J2q09DXhCX79f03DgbC/5+Qo8694A4uCzvhMSvCtl5X7iRX4b7Nw38GUzVSp325OtGSpRzv+fPqJtmlrMtYWZ3pX'
+        'sIWiJlony2aKwJX/w4fhabj+4HsYLyN4vFK30WfYDMltyx6lRpWuOPkvmBiU4GjauAaZGdKoM9400teKmsxNhurA'
+        '/7k6So4oR89nBYs8PHlrSHl0x3GycsdzKBam+84JI65RFvjnZ2CxKW+x9vITcLHizcsDRPUtyX4yDxYXRy7DMAxr'
+        'e/R9H3hR+K5g/8wTHWHwROi0dM17FLpf3P6r9tD15/inUhHcwGP4K2YpA+4Txo7bSTkmncvbMxGkK2csJTajrHOW'
+        'mo9FS0Ulw2nMdt/6yNQyIUdB25/yu0kIMRdfH6n7tm2ElXcWzeqprd35dpD0HNunFagiIWA7zXydQyqmfkyBpSNN'
+        'Ya9Uveln/LYYy7Zw4y24v6dd/p9Vy2UDg8gLBTX+7eTskDEkjkTIOfqy8Y0+97thqx7513znvU9dD2w5Urdgf587'
+        '/3Ec32Zk2ddKknZz+LYJVSKjbg6YgapWQxNE+X7YMtmMeCmBqOIaWgv/q4HI4SYmdyOBuoto9IUNbPKK7y8NSYlm'
+        '4/+rmPJxhDwsxJ9j8SPMz5RR/GXR+pxai9DrF/7VlPeW7YSB5ckA/PwD/7pNPW1AfypjKHDliwX/OZ4FbvGKC/8x'
+        'yYrH1219tfHPZJIF9V+85i2Xe4r0/yyuXBL7Rdi4e71Wk/8m4UhJM0fmkfv0wD9vHUo5fdq8R7G3G6t4oGPeG/8J'
+        'kTRNb/vZidDcuKSpNg+Yvy4d/WRKKk34kuGcZSwwhJX/a+OfcrkU3Ph3isIypb+44uaa3nEQuZMCKdY0xywUYeN/'
+        'XZT4Cvw/Lz9YuxV/b37+gf+bA4nFbcBesq4CDPtujNLRtnSRh/qs3nyob2u3rWj4+WZNTOG/8X8sPHt1V715zMAm'
+        'bzURk92bsj3vzZeOHA/h4kw2k0pGnpctlLT6m/A7JAkGx8w3tMA2E1CQ207d0Qp35bWKiV747974J63SjpWNISo/'
+        'wHTfltvclUNdoUmfLQuTvN2F4IV/Y2L0SuFKBkE1S1b6YdbjoTIzRNANfSaJhXcE808imxcu4GXOwH+HG4d2vJ09'
+        'mFb4STXVMBV5HutuFIVXZVWHDbCDqfx8YTE/sc8dbA/t6vf0sgzNHbrP1GltSwwq6uRH6VWEHKvCY9QrlZtZjp4Q'
+        '5mWH4DgpIs/rAXXIVVf+17JQi4XQ4k6v9kTS6drMWv4c8BHwM3gEQ8ZOmAGUvZQQsnAHCSstUSlAOYiep5/1K63J'
+        'EGWLtqkMGpXUVjEfYwFisJQjr874brChkENUkvB/KTuldLRUVSapqqvpS5emCejWkQ5IpcMmeTyLYdNAXVdylQem'
+        'rPfYl1wiMzgR7+vUwku25JMOVU3pmUAngXWUm8tuO3ZiJs+Pi6lvW5jZiqyusefvB3vUDTisgyTUXPbbNuEPN9Ul'
+        '+46/e6KX+Hnwg3r5Zg0DciQhoOTzSMkPgVeSFk/YRPoKAWL6mlPlRG8S0fhz3OWEp+D1Ll63IRThsE5r7iCvFWgw'
+        'IScCXJzsoTrwr2b0ob7ULc5X8vYOmIqwuubGvcKWDVNfcgRGgVUIryQzNlHJP2mFZ32XPWoV/9vvcw7sq7/HZzOY'
+        'ThO8GUsGgeyCejes05fVmlgJJnRqJ8dIjPLBjROpxZJ0/stY8oqr9fYOXsxduRtZb4zWK3A1buayKGjLxX6QX/2b'
+        'Y3iOLmGcLh2uWCynDOLsBRWMb8OG3bjGN1Iii3oS+hl7bmIQF/e5fDT+q/qHXXv5QI/I/7jqPKvlCKoq7xpjY6Uv'
+        '/K/YiTMX/sEGbnJP11o06Av/FXP1a+5eMqu9Cpsvi8zCSzBge4mo/Rf6LMLDrHMr3V/TNxCYc6FJvl/Fva90Uuyc'
+        'I2lvbvukauZ7oJS1vwgZO9Tp2tidydh3wEHpaz4IK3etjyBMIvK6MiY2w05oMyzVQ1h+KtzZAQTsYJVoSpXHLE7j'
+        'W5RvaFrLm+er/1mrz0XL2/2PELhKNy1GvqRqepB+aDcdYg1MI5b2PcELPp/mBUu2ClUP/h3qjoy/SfFmDFB3h07e'
+        'F3FCJH4M6ZrtY2biokbQlDheGIFeg4hEFu417q7FYadTZcROQJ8r9F2krSUVCTQ3UANb9pNf6ZlDj7XyP39X8DJi'
+        'Ucyx+eD/fMQrv1Cvs1ai5cisV293zDv+r3SX/FnT/CLsaTGibP2b7DNEmOCO3bOsMnj1Dt9ige5dmxZocieFPh18'
+        'yzoZH0hgX+eBIdMBg37jU3PlpFXV1zzP/4BomiiOUVhtGb3LhWAS0ASVu6Be0juvbrZ8l9y17eShzeOVYMEeT3pM'
+        'c1uGh2kjwmCGQM5z/Xs3Z7XGLNVBLmSoa9VaOBj5zhLG2AJzHoFJuPWdYUr1f1rPFgi7ijrpazewnadw/DdQLV91'
+        '6FdXjdRyxAeovMfE8Og1xzCy55mn31+CxQGTEBuDjkkigWZh1TKA9VbaDWVltymweCSuJ33cxRxmzDynv7mb0SK8'
+        'DCOHyjtHqizAPO9at1MwzXO+Krsct+U654Htj7FFZ7EnM2zfiRaTIGqKTdVhJTWwn2eBqqRGF44vum/BQ5+ArVIi'
+        'QqZuu2oVxy1js9lkc/RNS83YiLm3XXvJ1OV59xiI06b447wKo7nuL06/uagA+aTac3J+XLbpkPG5Oni1K+Ollv1f'
+        'Ni7Hsg7DcrLYdYRbDXIqqI8cpbckuz97Ui+MPy+/F6uNOl4QKAUib/+sqFhdQG8E4MJ/YkY2oWwcPzCD/wP+nzEu'
+        '/FfgH4F/+zPFDFyP8rtkdDz8HfrOe4qry6fhgRidz1DhNl9P3t7wLrG2Qus6AYiYx8seCPw7DgP/eZ2BcNL2Tb3n'
+        '8TR4Yz5bhTXswT8th0pu1e0Ez3/dyxKep1fUVuobhGBd8tH5a3nZZtbG//PO+YjDU5/rZwdq+TtWOsxCCWt+sww1'
+        'coCpqhgzooTmC3u1WGteE//F3+XzDT+wT6Ynm170fAzLbTTlmG+s5FdzhAkZrDLTjrXnEZ/uflQBmv3rfPcH2JAf'
+        'Fgltnmef7cmqaxmuTy9tWavF9nOrNtRu1b6NdwbUt6jSE+KQWWQZzKZm0WiewOwbPxkPM8u55PyMxIV/xgYunr4g'
+        'NEO052l96lH45xf3dN5YSYaMUVnw9cI/4jZQRkniv6Pdq1p3Qf3mrf22F0BkFv4YXXM+mkR38cxvBqoOu3nTj0/d'
+        'orA5OkVEvjUUEAljjSRBCNF+5UeHGi41azirXgZZzRU4btdFFtejXTPWZrJc9hHD+NCab483owQrufGav1+zlWrC'
+        'XUGkQI+Lupk48jyH4ErkncO8CFtz+xlMNO+rfF6nr5jMMLqpLY7koletc9vjuZlKGSl/XD+veern8vWEocS8d1on'
+        'c731uX2N0FPXnoG/Ec/N49i23Xs+0VxytpeBUa/lxcgGvNEE+FGczd9FE4EtU3kAW5R0AoQmXgsIZUE3SdYmB5Jx'
+        'NjVzbq+/W2f+ZAyD2Wmplt4HbqHfFcbvzQGs56ZX47rjTGEd/pO6srAEcRsx0pJ/DTa3cC0iO08VV2c8EXAvU8xl'
+        'WRRxeC5Gx3kT4/dYz9lfj8mdMQsVT/vI3O4afz86L/D8k3Kk0yqYVsMZx2TGicHAfxeT4b4++eQ5/MlFqmupTNem'
+        'geuHbu2SJ1FLPfqK+Rd3bvw/cgb303n05y/TJiY9avqo5Z8X9uZ6qRokmJ9D+l/456FA/rlcvFhLppuD+h/4lz3q'
+        'QuX4zp4lTq8TA//5UPhvk9kW63lfbPxazvPc642RX4bpWLCobe97wMG/8k1bt7SL9Av8UzfFf8rpQMVFXPpOjJZE'
+        'UMH7uJeN3+xIaZpODnzOXOu5eItwImkISciZz/AF/k0UelkWvjm3jNgZe/o8GsguzdeCXfnzG1Ws0ROKLB6uQnGZ'
+        'vLPhbtkItKnsiukSiYGf+D87ih9txdAFfS9EFNMepkGSTyLuNNE0dq8veqGEwDpmXjjd4dix+fU2bOhYWTjUcnx/'
+        'z4o8saL5eXyP0R+GCvUwNpXfUsDnvw+3cI4jP5utThna3sV9UljtQTqiS+EVvuCXx6gprROyN22g/AU4JWyN7rIB'
+        'O9HOWXYzJ/m7XBDLYlPk0B4djUCpv+Tf9v6lyoL5GAB2KFnHZAxdD8LtzevipyIv4nezFo//zvuoz7fuYVJUAHen'
+        'lD20d0rtEQSy4lqxWr+ng19/zEpl24cVUPbNOGap1m5G+oLVOYXUfL0xcgCWGVdjhqsoaHcxNRTt4WhGXmu2OZoE'
+        'X/Qtn6M6xZPtOrPNnGd98thrmHCgSe+9+3feu03H97p42zUbxS6FX1DADfaifYOEmRMypYuyMugiFpkXeo1+rfDq'
+        'H6ymiEzDb1MVsV22fvnl58NB7MXXiVPF4taFEGfC6sDIfdueE2ZAqlIyLDFI7YKebDtzmJgqsYkYk8VR2jz9uAuL'
+        'aBA79OO5N/hMyqtUUPM6L7hS/EHYtyDUJn1vL+FqgvqNgUkoixjSuPJPqTFTluKcNHDvFTde2In13kVxr5jc9vpl'
+        's/SlGkTFSYwDLgLAXBa6dw7ILMXAuuJOzcALEzMnbavU3qH/b/yHAyYMHAfCwzXXv/DP9XJeq9tBP7gGKPv5avBO'
+        'w1rG/3VdB+juHlSidSyTDdbd4MFx0zf2rG8vvl4CVCZ5hmFey2fCUhuTYXzkRwYoo/CDPeZhd9s14xNVV23heMz4'
+        '4Fs3xnDrWLVlRa1GlLeNOr5iPCxAxdwzUeB/I2rqxJubOuaPOXIBIfnWTbj9nc228dYWl3zcGXFih5H63Bf+/JIz'
+        'JJfq5EHAk3q/NmjnDq3Gu80f1EzfqiA21g6y8a8CYDKvbxtE4H+AZZVXeOezKPBkNSn3vPqA6y0TS9yFyvgNefNY'
+        'ddoX9RP/nD8iCWGf9yrDZQglgAXO00LP22xKaE3W/myu2s1xMfLatPOOnVrngeVXrJO/SMIZiM1qwZ+JxRqzHJLM'
+        'OL/zv+SbfWzGfLvSha5NCu78YUTFJW0P7Yf2hP0pNksyU7vx384bz64hju/49UiivYkE4f8SgxZCPh+NR+aVtNlo'
+        'rfzfYfQ6v2Q/IYjwziQDsLtftP+PupM4rWIdgHS7ann7+n8+HorhrZ+6Da4WidTKjCwGBzd8WwqMpSv/WyIR+ExW'
+        'XcDeidEVGYP1g82qduM6EXREaUumYiQn5ty5puDgzwS3iBwo/DAqfhS5mS9a5/Syyc0ueI1HHNXowvOwz9MpqF+M'
+        '1fskyXGvoXg5si+Z5OWZt9Qoa+d4RVtkklJoaCZxfyZGER2Zrh0EHVL1NYVSx6VXbwusTMSjh7bUuC3bXeOvK2OO'
+        'sc/G3DV3Uo90lB6O25A25u3y7eYsM3qSARuZqlXQXImQD901+n3zHuNCH4iOWHeedjEoipocZ3mux3/HP6J2WUsD'
+        'f3DvxO4HFh7NSXnuLGOFHVu20efBYFAydWbKg0nphV3NDKQUIeTryEVmc04ueie2u/a4sI1ZH8C0vnQi8pr271fx'
+        'T+usMU/yCd8HPt7pB/UrJbFQ7wv/LConKKTSO+o6oOETWXAj7IMb/zkbQq86xpAN4lrLVZck9r3kRNhtq6dzfXXs'
+        'nmaYMZ5W3qyr8biXLxyrnF/jt334C//Z6K4mjGN/ysEi9TsJWdctLLcxVv/AvyP7ekScdeT/560r/9++pSQD1eof'
+        '49tHxn80I/ZJOKbqspeVlG05/4pZyv8iqR9653WD/2zMz8f++n3hFOfFL4Xhq//O/Wh9R8x3IovUXUDiv3PtvLRk'
+        'QonKyF0Bi2x/Rx6sKB3HtAuB3nEM2/QVYTKJf/IebpnUCvw3+ufcuPc9aUJ9oqfZekFLVsz/Ku/6ZhRez51QJP5j'
+        'dN3aGWo5yYsCoKbqogbM2x2k0cbYM9z65MszTpT0H94KatzSx/QLPxvY/4f8r3YeiYPjnZm1O35JO8MfgylFYzSm'
+        '8y20euNGQ39q5xcOeGSP/N9RclzGBLRLF4Pwz3xBDdNJK/+DE7czI0MDSSqZH4PMeqWH0W+rt3YPQ+t41t7FtOiM'
+        'SLcYKo+U111xjZ/PgGOHidwx3Yj8TnCeb8TJ58WfiOBp176kmwR2ugM6xg2HoWsyaRiu7Td5PUm7ygUuMk4ywdT1'
+        'iNXG7iDYVNg63aY48U59qN91bne9hwh2+CowF32u6FzGqIoMVSaQkCE1zCo07NwXfeQcbRhGU9XyaqtBDElDN07J'
+        '5i5lz1s7kfKlajM2UJcvUr+u+hWjaX8+BzQ3ZUIaWfJi//XElU68zQxec55YvExVe8Q1t3cyx+zdizt+Pcz2BwNs'
+        'cEi3hkg0MbJLx2f7qGtXUi4WXsMER8CR9Uw0ZF5Z9C2cMl2BPow5q14r6P+qg/5I/hu+VfMzF3zJ07/wfz3V/F0L'
+        'B/2KstCpr6sBJYkMil6s0S839g/8V+Cfr5HxhLoI1zFt+VIGqp96JS7Gr7EAMUymM5VAWNTTztfZa9IfduI8lIfx'
+        'gtqYzpuGulCrDqnjf7FZv/GfqYnmUFkXMVgX/o3zqfmGjLHev+IAC82OkyyYolREbb9fEztews9c9EddQXneFL7p'
+        's65LpjbeFv7rwj82/nGPr1iktD/wXxn32JK0hKkSW/F5vQD/wn9XnG9eIf478v+a/jbcBUXa7HmeOKoKSwWS2xf3'
+        '4F+fS02eDN0fK6OWb9YcgX/q1GV778/8OML7zqsX/q3z2fv70tdzyfCOP5HEkD9dID/XBsbC6T3m1XTBTzHsVZvT'
+        'bpW+e3mOPU7QTYH//f22a9Dr98uZorMqtHlUpvI6iy61Spif1LjxP/elqgAHYvzFhqS34uUIc3rCUlTzZ9nyBxsb'
+        'Xk6o7vc3XgbZeyyooM9onSEV2T2fsJqd01FWJYzcdqZbjV23P1n24T0o3eU2rsA7fx1Tbvxc9VaJIRTXLWxsMqLc'
+        'x0bEr9otWqayPetaFmh8L2yVf/u6O762GMFVM5x2SgL/WuGZ2ykH14pntUtdlz4F7g5W21oVdpip+kX98eLrYpll'
+        '9/EAdkiUfT2bgcGhRdx164IJusY7//exVc+Ass8D6rOAMt7L3BAM5rjEzZf7y+65vtAK2w03eTyuWNez8BAmQ4iv'
+        'i2QMYD/5rYido3lF9e+cJlOELG5Mmsyx1y5Cpp8SX4Hn92F6PAPYQ5RRuLTTe/63i5CwVwjnQr6DDVPwSFAaD56Y'
+        'en877GUmaXUWkC8yIDgCdL8PmewWZcoRWBs38E5ulYkuGp1adud6Yl63kKPzNDsQC4O+LH1mH9Bu5y/PW/5dvrEM'
+        'GkjPOf954ZS53tbktAmw3g0q67Cj9c7ic9Dsgnehocuf1bKok8G0EKJbmNjodOiLJANcioTEUQTTnrsAzEhsM1cH'
+        '8ai4iiL2G7sx45/zOYLOqWfIQCx2UK6muL1rxFO7b0zvOFy+XbFTku95P3SQ6/unSHXQ2XuFFeVmcznOo5qOU7/r'
+        'XEI0KnqtKjaLVchHoG0Y/GwIOhuKCltmnJ2MIKhlg0UR2wsU1WU5qpYv6B8P1voWXDZNKxblj96+D/zLB9ewhpHj'
+        'dSl1Qxv2gmLtsg/Pg+SNN3LyOai4QV/wGq4u+40F1+0Pqvz2S45fIePo+611ayfL0Qg36fkT/+15TuEfCSEM5uY0'
+        '9O9Ml+0YQGVExt0H0LgY/BOLN/7JZV31G/837rbZZva+jSYRgnrCFFEmx7w60eaIWNw8NGJb/7Glv7AHz3+fWvnf'
+        'ZIjXZ3n+lFDd3nMryV/+h5qYyBgHFGQH7xSxQwQZZt0LhfmdaUc3v3rltBIDtT6fR5xEi86sswHfqyLr+VL+Punv'
+        'SF8r2fWMoVCVzukq+wy7BOv56tC213Hl/0XHgav48pVKnoeLa5c2pS0lRyh3Z5A+PX6LLzQV/pvcFuxXe4DOQ91B'
+        'WjYsY3WYc8Iidp06ubzKuaJ22J+hiX2C+Zz3JYpms80WT3I4MxeBbOx4n/qU4hiSLDD/C9FysgP2fJlQ3Qs+/cJ/'
+        'pxXjEd+SaUsV9cd9Db+Lhvmt4gJdZXmzKGNB8hz4U/NjoRhCw5XHyRnrWWfczeFjRIg3wVHGYHA+qRMGzW9SnaFR'
+        '2i3biHze+tSOA6jwHjVrW2i/fN8uDo2xDlWbC+s9xvm7x28lF6YteKlE12qCib2+RayXKJyTNt60kowyciNVG9rC'
+        'NfYxJ5mS8mt8uDkxa+1y+hzpl8wsvPajSS8jGznJjXZqbXq7m7TzvszRFfJZgLxt1MWzjFHOLL4yG55zqXW3jOYN'
+        '6VVkW69qa+gWn1wMllp37d/95Jn/IAqS3mAGUTyPIrU/9+iQDgtKjB1FMoZi2TnybWeTHXeqZsy0K5u0fqvBVgAO'
+        'O0+mRkkZbGRMVkPc3udzqvbLpXfAQwVmyltKIT/xXzeWkDESs9E/5STrgnlO60hve3gLU1Ve6005Iz2Wm7Qu/Lw+'
+        'RCr6K5v686sdRvpqTkCbYI2vWihlbvPC+zxMHF2+DDkmQT3n8ke6H58E/pcJ6/ZTr4bY557r2Hx0+B0jQxRHssHz'
+        'vMv27a0XG5gVnzP0K/Z724OFd8Y0hg+0q3EFob9Iwvo8smXRQew/dsyLayOdeO0L/zwrmqa3oXvZezekV0LoC/94'
+        '45/2WDqmrcirlcty/2f8r2kxuSPskHFg8XqFZIcqJ3befnlxO2D//g/873nPP4p0VF644nXHTZRkK6elRhXcN84M'
+        'JfvCf8VrwLHOJqROfOMSfYZv3bAoi82K82kIwqdK0lKA+D9n63sd59x3tbciXI1BeZ7qKPPnGzLH7177BbJ5eTJb'
+        'MXX3842n5/1ZOy8JhSrd4mXSOCbruSQwVM+HmmRWlcMc4WlZm7uQ9hCV9W8s4giA+dKd4FJizl5H2Kk3Vv+62ytn'
+        '0B83/uOio/RpOo+O4OAd4cCm+76BbCoYozfDG9VYHD5+XT617pY3ovwlc82GW8DDTT0u7ZspoS/qQqX11+hz9QkJ'
+        'zLBzC+noZfwfQVvyagpCPfAat5W6UFQzKVc9w0jBNsUtmefdUwR/MLvalYsR1fxRigkB4R8ZRpUITALeDSE5cGxH'
+        '2Z9pZtJdBUE/X4G/0PzO6pPz22cU7pizyrtQoiGZgUalzFdcDBcf0GCR7e3lEiM8rNB7nHOpLLbm63KhO9ao23p7'
+        'tCPT904USNrLEpDYJ3mXPatrmcR4bZWWaO6xE8YxgGxVZ0IY+JUFdvifvLfG8jKdG6jA9ppTSKz0Y/9IwnMMV1P/'
+        'ra1H+/qzM4n1nv5SZBWu1quXJrqvZZ0vHCCt0MOSaQxosvSP5uKOtHigVhpcTXencNzBtl/5uqUEFwMuC4xP1uLB'
+        'DMX75VVUay7Hfu4wZYHGgighz7iSZRUOjC1jhaNUJAhQXoJA5jT+ZZreiu7bUafIufCPK3ZYZGwK8jhcPJQ6HfJJ'
+        'ZYRF3o/mWM2YqddkTeKnvvRJ4P/qWKpWfFU0Du/f1MMOXdsm8O9xZkzRLy7IJXoq4n982S7sV5N/4f85hlr2tGks'
+        '125Uaz1Pf3T38kHnOVWX1GXfMkYVb56TPiBo87NdQDRMaVfsOGCxLV1HMGMu/NeJg1raqjmExz1xXrpOTa9FUhPW'
+        'mU/ha9I6xCoXyzmzzgjAC5PCeNW7YUwbzGi0Nee68r/K2v+Bfw2/DJ3Xy8hr19NRXsLUwn/V/uKD8iIlU1t17zoE'
+        'y0KVw+c8xL/jMYVH5a2pxH+axh8/8TGdl3ariKG6UlDX8oO1OqX6Ghl6fnkVIgWEtacAGCSOA7rXdeL7xLvzP9Xj'
+        'DphvQdymOv/OCkLPDojuQIo0crmEAQTKndwe3X17uQdRrjyS5W8APuX112pGI7Ps5btZ59pHXu1wTv00RYZ3bKuv'
+        'GgKRi5sGn1dDrMtXLPLAdn0FCAHyXAnd9FRqQrmJdeFfDNmKg2oh45wrwDzHv3YJJJHOp951fcIsmjkhbGJYpzku'
+        'WosTCuksglXR2C9z7qP+d42D8ym/XjMqtRH/lCN12ZFaY5kj03cD4s6NrHiNVS2rHCd03yNzJDcx2GPrZd5AkdeM'
+        '7ZmbeYLu/Co1rV26AUdc2nyDSQPtqsvqS8eTWIyGvxHni2EUnKjYFN8kOlfQKmoS1gOESm2W3gNNvBZ5CkkGNKGQ'
+        'WtIHSIghoyLG7xym1hWocsHYYrG5raIudaMua0k6U9Yd0VWLEmqzrLxVtJ30eYJtyonuPe9FvK3Dw+8hb9YXk7WD'
+        'vN5yzsrKS25E1EauIGXr20X2UhQ87p7IsncvyNV9Na7ByiBfKlyFgX6aQFbJCx80leJCOOsoKFC92J4xWusvIojc'
+        'uG+dNUTokuU4/2ap/DUJF77R0EZWjts7io3RGcd41K0aVJuCRFywkMsHJnEfzhtZsGO3kjZ7ZmZwhCuziP8Vd7T7'
+        'ZMZiYdwvIrENoDMrxp4X48R962ZfMy7GCAIdPc/Kq86JOlYXaUxVo1VRo2/9r3ksB4fo4o1a+o9c2itnbu6IBxuf'
+        'JSNMlS0eVoLIcXrKpzN3VyVXS+rkb9qrsO0X7+bfHIPDT73449qg+LHrj7CLc5oDivhU3HOudslDlocRUQOQ86wR'
+        'ecQ4Iz38tkuHzbFllAyBuTmBcRG7KxVF2JEh41vhBtvPZboauZqfKwvtNv77svt1rCP/Y/J/6h6BMzTaqxnWKcwp'
+        'wI7/nKuM06cemXMTLWpQlauZX/Yx2jGb2IwthB0rzrCVMA1op5jh/FK+yBF3XvQ7ufjQMcs6Lz4ZNGbHWjxLkFgO'
+        'LCHan9EqOBSbxo0K7M2tRf+YpdcNi5RkMgN8BRdY+HoFf5WDMBUUNfeavepyup/HvY9zfqYj5ivB5Jnzww7slLkt'
+        'rSaa80tojjAswRtsOZsx5cmWyc7s7EnOWB25iZpuvSg1dxqbUUxtHgI7784iegdpUhXzZ3cnoz2c2ecLVgqkQ/3w'
+        'Bz0zdscWayY7dwmqIdSnSjkhrwBb2Ubgn6XUCTkV83iZkeogNCAbRJhiYhv7FtYSt8yxlhXcxFZUChY6KFWNIr+M'
+        'hHt0i4DmD6xFR66R6hta8J05nmd4tJMDZ8cfY625ObS844clRmmqMdfk/1WGCmH4zYJrKPPz30AfK09PJuLmvxYf'
+        'kSmO9N/vVepcY8kic7RlngmS3u0x8+u/Hsp/6dUqx10kC9rUnxeEndtO97qlpbe5lhhdEdfJlLk4cJk98oa42dIW'
+        '941ZCb524yKxtcYzz+w5M4mk/8ZfMSxe489xxBD3uaPBehmvyYs+2q856KNZLFv2PaK2zu+O4Iuzuva8HxWA+3gh'
+        'XkGzVzCJ3wNjYIrioeeP016RKwU64CocWuNQ7xNuzSTr6U61UFmcSsMIAH7e0z+PQJkZua04p0yaO2J0x+GPCE/C'
+        'kX2qGJ5HNWUM2yNAszggXAYzYkSuif973Tb3oqstftYRxwJZJKZ+aZfmzu9+ryMegAXFjX3ZZS6f6v5ZuAywICsX'
+        'vCBSUzQVb7lVrDN1fbctr9t3Foe42Rxu69zlCASyRIlwr5W662X8xSiomAeXScy11/gaxc2YWf+HdaMBtj6R3C1D'
+        'X4LCxUH/cpwggdihQ724i44XQMs4w9Vwz8C5e+XPpAWJjuKM0475WEzf+M8YZWxpRznkIhmhHBPimMQ/sSeT3WCP'
+        '0CZlqzz9gX/56I1/0c2yU9dOGyizKhSreMVP7Vz4Gjt8CeOfkmb+r8s3gLUmL6RpV3iFGgWPixCo4zm2eBv/CPw/'
+        'g7VsEnwAwYr4n+9smeJ7LoEYT5V7TRUx+zFzPLxZqU2UkOOl0DslGV45Tacv8GuCOl7tndx5N3biKoMv7Fdu3v5b'
+        '221+M+eMa93TqH3c8hW/JK3Nusvz7ZUYu2wPHuU+0zD9Hf44tjAfHPgH/gPSEWOOOROSmwN+KFIVwqdU7Cw+m88T'
+        'fNa3AJ1RmDH4WU8pO9r8HftEWWnG968yhMyy/v0OrclvZuXYpwUcqxdesAlTmZXPG+RUnB+TdyBTsVdaChx6cSDz'
+        '/pkF4oGKrr8nzt1lUtu/19/TBsDzXvSYLwEzjKbvDmD8wH94BhvvXbTpZLoxjr0sQLX2C8lYmf9pGr7uAIzqxFqP'
+        '3IH8e3yWuVm4SnwvNs3vqU3aOXKdL23RmBE6cW38A+ASvfqSTBYoXXW9m7ok2ZpbpmCv2JWkfCgd66EEMt6SPY80'
+        'm14/9wmwlAs5fQ9Xd1a7231cxUnHHIjoWmzb11ij4J5qj5s6nadulnOWv8HPD6krAsOVpWtnuLliN2q0/XnRlnV2'
+        '57ibzMaggFo7Z0EKr2QdKSxjms/Z6A/nSD6+DotVfk5Sa4ftNq0q5tVzbPt3yoXyjjouG3mc7zUwfsQNVyfvJKTE'
+        'NCnBBT/fC5uk4IIN1kxYvqiXPMWUyLmYfuHUekvo9NaCi9tIj0+C86v7wTlqnT804yxSbu6I/yeZs8DAtmnVpu+I'
+        'vOCB9q0ZHSR745+yZRrsWtgVFEe+EMF+DB1pr4wFXrD6hYqkiHBL0d4b/0ge+17FMkeizfqXP6ysTuna2K2KT6eM'
+        'r8X/EUMzHwVH+iR1vPGvmZLHK2zfVekr6ujDmrmyaKWP21GquWL+XUhuRKlRu8qjW4Qbn6ggCckHHV/jJ/7b+E+M'
+        'RmVX+VDsYq6ngGVcHXf8G//OixEA+2zpkgXnjkXnj9f8VYvjvSgS4/Mc5DyR/0e/X/iXvcqcFMJvJGauROCYY9dW'
+        'KW4n5PdaVK9gljE68Z+gnlPmG0JDbl2sy8LCYG5jKTc1KmrTOrm7MJ/Ke+5CCc7Tp5iuqrxf49yvy0wdn62DA9gN'
+        'UBjiXAN1S/O6VlGId+hg8/hMf0boaAqKM68HYowB5N/3oDJn9FrGOublZb1segJb8o3emN8BmQGUkiOsxwJi+Pa/'
+        'c82QyQrfYuMw0h/8n6rlzNz9zbpt77Slj+gU8jMWaXSUFBhLnbmeET4z5d4NMTvpaMsPzHvP4E38Pc8PvIyUQEdz'
+        '90wIQF2fnG+Gy4RlT3OH2SQjyRzcdvlbYPuVH/XPcwlcyHbdCZ3suwaw1o7oa45pj4UuLQbTGQo8uP04n++zkWfQ'
+        'WAyPoz8ZlUcg8u7ri2E6/04RTCK9b//zEo13gPdUVZlowDFDwGzGZC3yQyVcyLppYzPldyXN34+8xZWAEkXlFHPC'
+        '+RMJYKHcE8EZSLqIsJpNRUWi/CXhu8VdSejOUDw/bKnbLfEe+x6TNvCtwF1Wvo6/4r98q0PauFckbLFndp0HkdLk'
+        'n4EQQe0xtNPWzj1XMjpHW1doxozZKmVCvSfB9G6JFqk3lepAxGKcZmzUEr5vPwWgELmEWPpQqn77SFQ9hcf9m6aZ'
+        'NlxrI+zEVbf6NygoXD6bECX7l2zpOFy3cAZtY8O0Kn0hG8dcUaB12N1n0F4zMLYFev7+S78nuUgU1ItSXuTICyf2'
+        'iOXPjPYD/5MIdB3tpoY6m5LexSzeg70UwIsb4N3N8iC4eYKuq87TNFuLCDG7zbnkQZBi65z2eU7b+P9BPqsQ6QX0'
+        'aSo67T7nTVykfRD4D1hZ1jnyiqPOOvKVDDUmcZa3RjLmWQNRhG/HtSl7uPolKDwOF0hYFKkNo33CIK4HwudKYPP0'
+        'Y9vlbabCfwWEL7EROblSXJH14D31nbhoWRG244VRXs+5aJYzPLRzKb5GLgOIcIqFLCG8Fj5Wbno/yDXUn+79F3cI'
+        'X8Q/tQQW71LexD9j0H5mNTfLMRnwe7C+AnukboauQde3F9dBptWzyzLf7K+wzil4B+Xclov8PErm/y2TX3xw3BKY'
+        '/qkZhk1tznIAr7B5zlNga7AudfZbiA6OgkroKhVrYKZmjnG4fxLDxP/aCWxGjj6V1WM1JLak67d41mH6KTnG5UTC'
+        '+lzhGUoB7NKFfHHE/+xKVUp4rXl0Dh8yZHas3PjPcdPkxCQkY0QnWpGZnIlF5fwR8+f/c7rAcmzzNDV/6P7wNmNb'
+        '52QCPStV2uS65jAiDFp/PJMCZ4RRtevukTijSVIYpXoap9uMLPSHoNlsHuOz+Rzd57ZPsVHUzU5SOqTVk7291nMb'
+        '7GIAEqqE8t/l3w3lpxH+TwjvBCaDCFKR1SAS9ewxr8TtnBOVZqW6lRNiJZG+5HBziRA/8/yDWx1HpobSje5lxOh6'
+        'FiARB8vaM95YS9fPkMVoYszxf0bnjEI7YjOGteFsfsYdbIbB+0zIRhxItzP+j7H5+BCawI3dKXbcONWP95Ph0xee'
+        'uZdcJcJsXZe4rbiUcyMnkx04740A/nX472e7oGyxeYg1tmDzKZvOKK0gDnrrW+/aMr5sg7AJIzPwJH+2LvQu1D3F'
+        'xDYmgX1ek0VBzDFdWLFIqYwhXkOGCvzfst1RxdX1yGelEJbsBptWAWF0b3JzZCop9oX/+Udfy5+Ec85/JmxcqEBa'
+        'InS4DzCNfsVejg0pNauPLIxXKoF9DFSoerkzUU1BdowkCzh5DSePrHVhU+yHHTpYBLrR4flLFuof3MXTXACWrnFR'
+        'fOZYt7Yujm3hW3QZ8RlrwktG/MI/LmFgu+Tew5CMLiW2slHBZVPGNjHziZhKXtl476SjOQNqfvh6RWHIkNasX+Y/'
+        'ZebUINY9Mb/vAOjlG/FG7eDoVCLwrxi9GpB4R/jn6xt2p0m1ZpS3A/957uIv6lmXORBxFPk/dZZdA/8RsCsOLqDM'
+        'aGGHxH+7SSf+s9lsC15ZyCBSCeU+Bhs6uzIr7WLT7MBy3bnsLQMzVVl1D404qnfaH9owR68paZbjlQhkL+3QbLi/'
+        'idKf0AmMNMfpprWGxMeWLjyM5BYBTpXFJUUWHectLr6M3/rVdI90jwGyvj2/mTFi9YT0+RDhaiA23llI0Cax/zA3'
+        'J37EAx/a4JGbzkj8w5G5ae/8zsiFf3/lR453xsRpqugU2Yy2OEbJ2MpiXXltkUUEOexZmmEXN4n/nooKJzz8u4cd'
+        'K8qdOTPIgXCGSu5zuWPkOHoWmCBCZlH+iAESsMNpxYMJbNSaHU++PaE23Dg35+pLXDiVeNFkIiv2BWjU4KhPfzbv'
+        '1c4RBS2A+TcsS+020t5Vr9xe0guDf/9OIO23ccFGEE7SuAbtawErubU9IJsLRU3YxPwYK20/Yy7tWFfBOHcvCPYl'
+        'buvQ51zXKf7ETWgRhZzcMIK6/jLiu7fdpK/i1s1ETdrwXKlBiTWSa4P9ZMO6xuBh3dKoK3xMz0N3sktiPLW3QuXQ'
+        'vQKuL3EIkb81hmGWscn6HEEFV9tfaePRZ/mjX5ZOf7avi3kWOhhppTjZsYh7+BJbEKrxWZZYeIvziR0P4xicvzT0'
+        'KoIcW31FQCTBUrHB2AOu1eslelEDXmd3GRu9Q94x3r2JBBuDP5W85s4D0uwqLPBr7NdzxnMtrjrJhQVolwIoeD6E'
+        '0C5TjoG74dUQGqiyIM9k2bfelwE8HqI2oKxb115jky1gFmDxP9eqQey00korukbGm7cyx6cM9YN7WWxg+JXHOgWf'
+        '52x2CG/Hz4Wtwr7NL7hSYgb++x/4XwTcQRs8pEqCM0HHjbfax9YMreN/t+d9k7QzwVXyONLUnhkekzGROGRC7Csw'
+        'Vu4jjuG8JESHLVQiYON/4Tv1vGKR+K+N/3XR2pXu9aeuokq3YN/4N71f8tRv/MeB8wcIvXtx8ebWH4FN9dqY3HVG'
+        '4H+X0R6/r8imqc0uY3ZUx2/zFvS5iJq/swaqwHo8/N3sCPgOtM/RN1LgFL7nmIFz7vuUVkwV9hhelpHdbMBG3Eq+'
+        'ixTjr7Xj9rFEMRrLG/3e4aNUuVtDstTgXy6K0UygL7GXhyf2j6sA5dK4ayDWfiFt9C5rnjb7zvzwosplE6BfRFLH'
+        'Jn8D+LOAzzbszDNMhn0fxDErOxnr0zNHM093YS3S+Nn3kfvPjp8cNxyIhezmlHjjv/YAnfF7v+NDB/9HWvjLSOje'
+        'Q9HInUK/r9TncK5yzKtR6jqf/oSB38UfuS81g7kTmxcfM0jf1mtxJqA774v+nhANrm+Gw5jmQQzxX3tRFJclnwAD'
+        'HHzViLNaObg0Bra9WkaLDNirv7Huf6fPfSSgSDFQPKiQ+UN7KQIoz0NMtW9hqwlA3LFyhpbBQu5L7E8OPop2FNOc'
+        '3wyCNUdtwC55Q+pNfOVGmOc458Y44o637f7++4YTTIlwRM9oy9RzVsvmtmFFGHCe0uyTygiDP9R9eIwnbut2ku1A'
+        'xnPPeYNUvuzEv4xueWTzjOnLhrR4hPnSh+hQHefp3RCTRdYPdPWqBI+svYplPSNwau+A+rQ7UJpsXIyPpnli/Fds'
+        '3bkq4hhOQRX9xXr4M0ueBxLHKesWfaSsxQ+S0Q2DoHTJ/vf+JyJLTYiUfssr/Gcs38XtZYcOna5FxNf4OsrSZsCM'
+        'fKsyVvxGJPGFdSiWchbmOUj/UuzuqJaBZ6oJ2XMgdxPLk2I7JPzp+ZP0aUM2Gb8K4y+/8ZJk8jjQO5KPb16JKDkO'
+        'Wz76ByHJHAsLl/xQVhxhCOvYumpdkyOljQf/WOAoNRUkh/w8C5KTamSNnLL88JcJ5/0j7raqm4eIzd7yOebqNYbs'
+        'cOP/az982o19GOplt7UL2hnvkC2RCSXk6X1qEf8V+Kddb/wj8cZCZGJiyRuPT3zVK86B+tnoMb5q63pZK7KHlIrb'
+        'hE85pPEugbrT55EzIfWfMV3fxRiJf/YDm1w1CToDwU/7O7GF8PFZENo32ljHSVfnnMD/X1PYo/m3IuVx5vnGaaht'
+        'OOCBClqxwKCUoym3XpVJl67NOx1YGCF+Syt3yIJz3ySFMTeH3+/oxjPaf45Ng1T0RbAMRg5aaWxm3yB2crBxxNaw'
+        'aRvDanqrspklGf0TuUvjb0LgmF03xCcwacO9/o+bDnrLPSM8phxnR4W3hmGTNKVsZM2IbBJEBPdxcOXvDB45CVol'
+        'zkJY5/nns+t/b+vlfiHLp2NDla3i2JzlkzqP2iOrala4LI1c1IF//U4gXHgq8ruWB6qLX+ICzsu+r3IxoFDRoIXS'
+        'VCbzP/LN9m4kip+Qi+UZ/ezDoWH9fApGuMVBxNMT0+HBz58zcG7/XAUkzo/Fr5gzAnk57OFMocZDgimyTjE3+rku'
+        'byfo4J6Zm/ZBV1oDJhUVA0k2XZuYLZQiK/m5u6+czL3luJKBD9vz/00TFPXj7huKWFg7koTmmwqjF2vLBAXPki9l'
+        '4gCdNiAOMH5r6/H9u1+dq5BHqWWTaaIy+mSVLYEuD7pBUIbdLDE7Fg2qqvL3mMrpaLLRj9lK9XV+sIDNSzoypfVO'
+        'tnXB5d8VSoovJT/L3bfynuzXDnO+lsTYLcDgOPIFljrx2zkaRyw73Nsx1l04PSI29WB0zhnft52V381yxYzhpYqK'
+        'nRUUC3TKj5C0OuZL/E/gPiN2hR23DOjeBWmOh2vhQG6626xsdPOx8S+a/TFXcxW5lZDWOJ+Zo7NI4uuqLL6Wvyg2'
+        '/cQmJe2QmpwBtgAvrCk2MTqUvvzlG7uQsUnguKIe93i/HgagfJHN3eKK7mjYGEszI3Y8r0J9YbWiWTB/yDEcBuYF'
+        'cpqNjLdcGoen0Vctgv7ZumDbqEf+rby3FLq2U3WcfoDxw5y4cqYMuxdrMp7EWYkp+QTrNe3yy78sh/vCvxt6L0Sw'
+        'KZJCOadJ1f67XvwL/1XGv+J4MImIPU0xqSM9RX+uwqssw64HYF6vbbsguHhv/jZjti8OcZx+GFPwGDCcp8c+UnTQ'
+        'W9xw8m19veDmsqA+4wlL5ucb3vr59yTETg3mQhaOieH0qeSuccNTOh7Pz0cWcC/6V2VbqdrieIrz5t1B15d1hLlO'
+        'WJ+VL6MWbsKwY+jskhXWznjr9tf0ncevMlej+GH3RjQkJjFUxAqONIFGjFWNNqwgVEhbIErTtFuIzkvzNlQQ/8kH'
+        'VbViaHaAm03SWbypik/eKZSl0zSUNYVzn91dGNAa5bFPBds8KgXmuQPddn4H+FQ2n/gp37p8fHDv2s3OXAmozXc0'
+        'OGytoqT8Hcscqde+03eBZq4lAcqbpW/zM7pmObZPvv8ykf2JLmUaV+5B0SY9MDhyqiSti6+GZToWOlJzB8q0im72'
+        'ahqLZ4zOjxqM/T/UQzS2TpmzKO1V7B4LIYoufqZvFj1Wq70krmggHLH7Ns3wd9VqAHI+4gbCz4uINAsW8yaQ47o2'
+        '970aI8zn6WJUa4eMxarkhHve8LBvXcX813U7NhvIZLFMmisxiD1a13havMcOQLEBSl/tG0n6moN24DhZtD9hqzhI'
+        'POe4Yrx8kLoGm9JzJYeJjjDCXjToiL8Vu+dI8l8Yk9jPf1M661cu1pjV4iy4Olp6HbPskVVkhd/qgsgex5b+dUkl'
+        '89Z2PflurWL21hKBDRY5tI92VDiO5o6F0CqtM2IZLCfY+mfWy88MdijZlT5SBVXk3hV/aYuYk76yObqyImRhSp+w'
+        'tPM/5XllIVy7AAhKCV1ld6OF8XCKqTf+N+cJCPlnPXKXr8aG9Gcn/q1U1IywPSZ8V1yUxzac2zaozRVqfuCFtGxE'
+        'GJfrtsIKRyDkvzTuwL8w273iscSdG0y7YLeMI9Z8hbltxsZMdrvwps/XzXvM5Z/x5+ZE87htQd4d225jVF7OOjDx'
+        'T3cVQr6+F4lqYUG3kXdp3jhdOZWNlGOn10nEy8ZDxWJRSVe7Edex7btIUOWN7ZYvGEfEofduXlGk87NB8/wl/SqG'
+        'gIwZTWTgX3Z4ZBsMxRjLNzf+vQjaxTbl2OqjRup5Z2bJJH0BYd7I0MT4FIEfjqBIZCk1N3i+8M87FHpfJt+vXbV6'
+        'blnt6RSSF84vMB/Ow/9VOl9j4Rju3uE6Rsr8b6tAECOK3KgohjCesW537Crymhzl10/5GYxyfU1uLcfSSx3O+c5u'
+        'LfwTL+UVnjN/jx2rxl1inL6rq9rFPlQd2dIp3erCfDQjp3XLafuGqhxF63oHySZAyTfggIrF4S3IXsH7U+NFGYfA'
+        '3rHEAv8Qen7G7nx4EHpXeVlb6sLxJgERK0lCnxMcCdVskMDOn4m0+PjIU9Q5axAjg/+d/0MQt9xuw8v6tVkWb+aH'
+        '5S4s/nF7lX7mnq7jCQkg5NV/O4SfPW40mUY4KhjCx8qfk5xQuuSl5yyk915fDZ8hnXEIYwyatwKqKDYZvmwZiVfW'
+        'u8k4/1Hu16PXnxLcE4wMYYK731Nv7kRy0DQQoV/VKjb6SkxboH6LqywQE4duuK7InbATz1i6GTuSrnhWUvGSIg+F'
+        'L1yk8XW/6HzJ9/fik9e3ip/KGNtMXPvBlNQ7nrcVHItXLPSANs2JvAbrzPqhdr1ia8Yj6QqUIBH4GjiBJEHv42Xe'
+        'X0XaOJbv6S2Yz0/xZOW+1yKTiPPCfyEaBcnR1RXzVe1bpxhoioOwGCky46XgeapmvRGv+XYxT3n9lOcsWTsglT74'
+        '9ej38y7CHVWJf8nwFufv3fVZMliP13yCWq9DeQ4SD/cj7PGtiLc8ZZLbvJA+hmSgCm7wOpDABiLH/AWEqVjUWDXP'
+        'Rfrlzme+XnwfJ2QhyXPw4sQz/o7V29d+L/HI8bDwWcZiFskr+QdkULWbAcZ1aRFDmIf+qWyQZApWtoy1rn/iX7tz'
+        'wSPy5gQNqG986d13FbustPDeLQz8Jx+vZidkrl/6/Sphf+BfFkTNIkmpxjh9Dlbs5Bi+WkFNUUt//oX/jpyax674'
+        'NmZ+InGFG32dIl67Z/vGFUr4lkENUoeoMSvj72RZLFHUD+LbiJDijh65AFCU8pOA2oXT63IIILsDaldOvnObKt0w'
+        'Sf8c6Nilg2gCURJ3X7TaNoj02wlsZISNVhVNBVUBFG2jnwiXMr7MWlqVmMi0MEHtqLx9tsML3tUEwhJjUTUoMlb+'
+        'XCCSZ8d/n8i/ui12dDkIjsZxQoeVAO1n3avUSFDjDOO/0T7kQOWrJhb6oxWtTrg9ko0V9R5nflLa8iqQ+f9JN8T5'
+        '4f+O/G9v1hv/XVgsG49vv/G/kfyGdaeM/ilL56Hn1bU/UXtdRm0VCBmEwGO9aB1z7tk9vNhp8xIqyRbVbz3+g+P5'
+        'muuPBrBxNZLgTuZat+LV1aRFQu8ci8f6YjhA96Ff5X/1PwiVc3X98E0LuaXG4JpvCVelROn1V8/A/2k3k8NLZad1'
+        'YRJVuzcY9msnLqSOnTxZIVNpblNmr4RS/W87dW975Lh54G/8L2Xktd+Ok2FZq879KYh5VXDvv+vq/MSsJ7dNafdm'
+        '/CPOsa4J+xzQO30xro0Q52LJ0SmJsqSvXcm7fU3nNc1Vx/nf+OnbjiJFVF+DVe3dwDC7F6RAmqs2oMpFWOIA6/Mx'
+        'cbgWyU18fmXv2rE9r+fbkV3oU1/h5LBY3FYSclO/y3jfiAXyRfIVr73iWmfkWPwbdvWuYeuYbJAVEyJmxFuQPd0g'
+        'TZPc1xcaDQkj8L8VKCYq0WdV1auon6Or9Uew3Pj5VWSaROrX4zFruym/qOCcwyx0j/HtvUM2sjI/8XXPGAjcd4Af'
+        'qTMuTljWG7u3Y2obO7ihE0/OcSW7Zds258bx+6EomDH8udJOV/vcJOnPhTcYL3J68lUzjnuN83fsU1eO7g4+G/wv'
+        'bvqNf+VaOF/RbysEkPZO2SooF6vhtP2h94l/o2nrWjf+O3cmEZgFuw3pGKL6Oa/v2tjnNdjxWul1RFTA9tBuqIEX'
+        'kdnFRk/CyMzHz68d7DA08W9dXkTR8UVRiFCswP+4+4dNtOLvaVvidb/vggv5ny8KwDkvRTqrrYrekLvZUNJbPQEs'
+        'a6seKK/3+3ZBe5eHnhILHGzm0+/fLS3Pbqi+EOfbmzVHrbkF9BE5s4E+IZjxNNUVTi3fyvozj9jRptX+1M2daBNh'
+        'R70U3rA2lnfM+WHzbFWoz/F/tb/185yIyLWsJGsq2dxvO5YotF7Zpt17xQRhzh5uSBtWNno9Pxwu/8K3sEnFJLux'
+        'niCmj3tBjTn/w22zsbZ6mRv7NVgr4R+Le6pyjnKMHcZmgpwv0jIjjVPuRTBa/ixBHZtLdfOufsphHInJhaO4uXpa'
+        'f3PmcauaYByb5CIJ9axfj4dWyws25aSYFvtvDxB/xWp+MQzPt5oy/2A7PyuYt9X53DEOsg2GJCs1PDbAVNHpi8J2'
+        'WDAI9lQrQ3iezzQ3vHLpD2OYOudf2xmBmLg+E/+cxQZNjUCaRgljzxYDRtN2LjJN+G/mATWnoql+zxfjc44fHqu9'
+        'TLiYSNfL79BF9b5hgdx9LTtEeOEVZ3HS/ez1hP7UaorfRYph/4CxlOHUCunXQyGaRfDY87VzrXn3ccY+HMcUp5xP'
+        '5tnQsJInX2fsVa42PwO1iuJig1hVmbZCn/MWY8wq4Yr7imO5Ot3OCis2+FmL+IxCjBf2QziHSjAZhLyx6rnmEXqX'
+        'o8OmGVxdWrNWwpoRbn/7Wr7fPl7YPoh6/8Z/TXJMufvC+u8WpGQX+oOWQOCeq4eZiLh45iTRl24t0RQj/cNN49PF'
+        'PMkH7WOLUXA3/L0aoli9dTQgoyJi7oUhuTJkOjrJk+3xtuYxtnAI+zcwkCZIqlHMUeiqLOaGbu1P+G6qYlOjgPuB'
+        '/w5D7AWUUhzxnB5b78Ua1C/8k7feu7tzYSeTWlkIdIGBZdXIBKFPBf5XtVaxGJP4H9sgLN6Wd2eNCIDEv1u7ChuH'
+        'bIP/8xX8xn+CL10DbJ0Nu43Y1j/lBlMD/Fh821PW3cAtc5U+e9apY51CdYrvo1mlRGfuD95LXyzMGXOYzxpJFJBa'
+        'vNLM60SAeB/T9e3if0w3UfDorR1CyXSVFPxmTF6Ni+WzcwWb4ClqZf3blvq6gx7SisIGRE0vV9Si9AlM2Hv2dJ9W'
+        'hRedhfJic24Qc1OHacB2+/BcfQa4yn7jLujJ/x2/i/7M/i0YEWxYyf27Vbq+8EY2PNq3j/7C+tgIMMK+ewFEXqLe'
+        '3XV/icPcWiAVyFwNNzyR69hXT/A/Zrjyv5y/gZwQQyxYnB++/Qbf3C3Q3+sPM7hZZc48AQQwheTNeoFQEi7YdJ6m'
+        'GAochF3HBhvFobf4pwtKEk1T17MLyrTTk/9x5X/BIV7LWdUvy3EJ6f8Df/28/5DZgEgAAAAASUVORK5CYII='
+    ),
+    'bar_indicator.png': (
+        'iVBORw0KGgoAAAANSUhEUgAAABQAAABGCAYAAADMz7TxAAAACXBIWXMAAAsTAAALEwEAmpwYAAAAAXNSR0IArs4c'
+        '6QAAAARnQU1BAACxjwv8YQUAAAKqSURBVHgBnVhbousgCBT3v+e5p60aHjPYXj+aiDCgIJAa/sag40W28euYeskG'
+        'qBI1PmuzYzFBOTJlc+gsVDY4RWZF4bTBN4KRF3A50c/qDEeP5/mmGRxoPFN+VH9Ksdx88+dXPsfyMt12OnDrccbe'
+        '1txIRcB6e7w+279vC61VK4cZKq/twGan3RiIwIDAO/NaeFfWNbMQ2DCHZJc9i3EAH+NWvOHiFEE7gNXLSkzH5ItG'
+        'k8Nzc7iVne2z3Zg1vhELU/PF4CggxpVMYpR7A19kSR7CwoqAILDpYKxGAZHkV5a2Ks/GjDAPa83FfpJPmgD+Vt/s'
+        'oJkCHFLnHfcCaD22cDA0YMZGdnCuXdvBHBAE1br1FPnlLosLcJ4myum+VzP1ApTTgjKRMMJN6XIfK4lIz803yhmK'
+        'gBVRDh42Pjp1wLLBeCYlE9K34T65VK25313N3M7lrb+Cuu1BatBd8+GtRGzF+3c+06R8fGeRf76rnifXrBjJsHsP'
+        'NvuAc2SkxB+Ce1Vq3X2hytj5OfIfZW6iagqWSfGEMvggq7mmLO6cBCDF64g1RdwQlh/YOGHTjd0zmRNCwyuzTUV9'
+        'XttmiZNrAoyWaTtNf4062e8ywyAW3o++JGsgUFMrQkJF9DTn1pit2Wcrk52XgB4ces/Wx6Nv1UEY9x3N1zHo819V'
+        'rVN+dMiy0AmPapxHv7oMHjBnAGQdJHuTfDtJIJzrYMqMkbPOIzufALBwwLos5FwUZ8Up/+GHINx8ViyOMm9cYyI5'
+        'iFIVaQKXAlrxMIoC1QCQz4oRPExvD67fKfpPnp2xHbqzDgrwEnGp/J2uF9WFbcZmEfcMi9ULtKY4ZgccrFWssSW+'
+        '57yuVvtRz1C1L4log4qQmpJKZhYeqoMOnxWH8MMtRnqPZzh6LJaBjNvxD56PBmvOyasPAAAAAElFTkSuQmCC'
+    ),
+}
+
+
+def embedded_asset(name):
+    """Bytes of an embedded image, or None when it isn't embedded"""
+    data = EMBEDDED_ASSETS.get(name)
+    return base64.b64decode(data) if data else None
+
+
+def render(directory):
+    """Source of this module for the images in directory"""
+    entries = []
+    for name in NAMES:
+        with open(os.path.join(directory, name), 'rb') as f:
+            encoded = base64.b64encode(f.read()).decode('ascii')
+        lines = '\n'.join(f"        '{encoded[i:i + 88]}'" for i in range(0, len(encoded), 88))
+        entries.append(f"    '{name}': (\n{lines}\n    ),")
+    with open(__file__) as f:
+        source = f.read()
+    head, rest = source.split('EMBEDDED_ASSETS = {', 1)
+    tail = rest.split('\n}\n', 1)[1]
+    return head + 'EMBEDDED_ASSETS = {\n' + '\n'.join(entries) + '\n}\n' + tail
+
+
+if __name__ == '__main__':
+    target = os.path.abspath(__file__)
+    source = render(os.path.join(os.path.dirname(target), 'assets'))
+    with open(target, 'w') as f:
+        f.write(source)
+    print(f"Embedded {', '.join(NAMES)} into {target}")
@@ -0,0 +1,16 @@
+"""Unit tests for weather_display's pure helpers (run with: python3 -m unittest)
+
+Importing weather_display needs no display; Pillow is stubbed when it isn't
+installed, since only the image loading paths use it.
+"""
+
+import sys
+import types
+
+try:
+    import PIL  # noqa: F401
+except ImportError:
+    pil = types.ModuleType('PIL')
+    pil.Image = types.ModuleType('PIL.Image')
+    pil.ImageTk = types.ModuleType('PIL.ImageTk')
+    sys.modules.update({'PIL': pil, 'PIL.Image': pil.Image, 'PIL.ImageTk': pil.ImageTk})
@@ -0,0 +1,43 @@
+import unittest
+from collections import Counter
+from datetime import datetime, timedelta
+
+import weather_display as wd
+
+
+class ShouldFetchAqiTest(unittest.TestCase):
+    def run_ticks(self, start, hours, schedule):
+        """Step a minute at a time like schedule_aqi_update; returns the fire times"""
+        fired = []
+        last_hour = None
+        now = start
+        while now < start + timedelta(hours=hours):
+            if wd.should_fetch_aqi(now.hour, last_hour, schedule):
+                fired.append(now)
+                last_hour = now.hour
+            now += timedelta(minutes=1)
+        return fired
+
+    def test_once_per_scheduled_hour_over_two_days(self):
+        fired = self.run_ticks(datetime(2026, 3, 1), 48, wd.AQI_SCHEDULED_HOURS)
+        slots = Counter((t.date(), t.hour) for t in fired)
+        expected = {(day.date(), hour): 1
+                    for day in (datetime(2026, 3, 1), datetime(2026, 3, 2))
+                    for hour in wd.AQI_SCHEDULED_HOURS}
+        self.assertEqual(dict(slots), expected)
+        # Each fire is the first tick of its hour
+        self.assertTrue(all(t.minute == 0 for t in fired))
+
+    def test_start_inside_a_scheduled_hour_fires_immediately(self):
+        fired = self.run_ticks(datetime(2026, 3, 1, 15, 42), 1, wd.AQI_SCHEDULED_HOURS)
+        self.assertEqual(fired, [datetime(2026, 3, 1, 15, 42)])
+
+    def test_no_refire_after_fetch_in_same_hour(self):
+        self.assertTrue(wd.should_fetch_aqi(6, None, (6, 15, 20)))
+        self.assertTrue(wd.should_fetch_aqi(6, 20, (6, 15, 20)))
+        self.assertFalse(wd.should_fetch_aqi(6, 6, (6, 15, 20)))
+        self.assertFalse(wd.should_fetch_aqi(7, 6, (6, 15, 20)))
+
+
+if __name__ == '__main__':
+    unittest.main()
@@ -0,0 +1,39 @@
+import os
+import unittest
+from contextlib import redirect_stdout
+from io import StringIO
+
+import embedded_assets
+import weather_display as wd
+
+
+class AssetCoverageTest(unittest.TestCase):
+    """The display has no per-code icon files: a weather code is drawn as its
+    label and its category's gradient, on top of the core bar images."""
+
+    def test_every_weather_code_has_a_category(self):
+        categorised = wd.CLEAR_CODES | wd.RAIN_CODES | wd.SNOW_CODES | wd.CLOUDY_CODES
+        for code in wd.WEATHER_CODES:
+            with self.subTest(code=code):
+                self.assertIn(code, categorised)
+
+    def test_core_images_exist(self):
+        for name in wd.CORE_ASSETS:
+            with self.subTest(image=name):
+                self.assertTrue(os.path.exists(wd.asset_path(name)), wd.asset_path(name))
+
+    def test_embedded_copies_match_the_files(self):
+        self.assertEqual(set(embedded_assets.NAMES), set(wd.CORE_ASSETS))
+        for name in wd.CORE_ASSETS:
+            with self.subTest(image=name):
+                with open(os.path.join(wd.PROJECT_DIR, 'assets', name), 'rb') as f:
+                    self.assertEqual(embedded_assets.embedded_asset(name), f.read(),
+                                     "stale copy, run python3 embedded_assets.py")
+
+    def test_check_asset_coverage_is_clean(self):
+        with redirect_stdout(StringIO()):
+            self.assertEqual(wd.check_asset_coverage(), [])
+
+
+if __name__ == '__main__':
+    unittest.main()
@@ -0,0 +1,63 @@
+"""Time-dependent methods read the clock through weather_display.local_now, so it can be pinned"""
+
+import unittest
+from contextlib import redirect_stdout
+from datetime import datetime
+from io import StringIO
+from types import SimpleNamespace
+from unittest import mock
+
+import weather_display as wd
+
+
+def pinned(*args):
+    return mock.patch.object(wd, 'local_now', return_value=datetime(*args))
+
+
+class PinnedClockTest(unittest.TestCase):
+    def test_time_phase_boundary(self):
+        display = SimpleNamespace(phase_override=None)
+        with mock.patch.object(wd, 'PHASE_FROM_SUN', False):
+            with pinned(2026, 3, 1, 4, 59):
+                self.assertEqual(wd.WeatherDisplay.get_time_phase(display), 'night')
+            with pinned(2026, 3, 1, 5, 0):
+                self.assertEqual(wd.WeatherDisplay.get_time_phase(display), 'sunrise')
+
+    def aqi_tick(self, display):
+        with redirect_stdout(StringIO()):
+            wd.WeatherDisplay.schedule_aqi_update(display)
+
+    def aqi_display(self, offset):
+        display = mock.Mock(last_aqi_fetch_hour=None, aqi_fetch_offset=offset)
+        display.ui_alive.return_value = True
+        display.is_quiet_time.return_value = False
+        return display
+
+    def test_aqi_scheduler_fires_at_the_scheduled_hour(self):
+        display = self.aqi_display(offset=0)
+        with mock.patch.object(wd, 'AQI_DAYTIME_ONLY', False):
+            with pinned(2026, 3, 1, 5, 59):
+                self.aqi_tick(display)
+            display.fetch_air_quality.assert_not_called()
+            with pinned(2026, 3, 1, 6, 0):
+                self.aqi_tick(display)
+            with pinned(2026, 3, 1, 6, 1):
+                self.aqi_tick(display)
+        display.fetch_air_quality.assert_called_once_with()
+        self.assertEqual(display.last_aqi_fetch_hour, 6)
+
+    def test_aqi_offset_moves_the_fetch_into_the_hour(self):
+        display = self.aqi_display(offset=23)
+        with mock.patch.object(wd, 'AQI_DAYTIME_ONLY', False):
+            for minute in range(0, 23):
+                with pinned(2026, 3, 1, 6, minute):
+                    self.aqi_tick(display)
+            display.fetch_air_quality.assert_not_called()
+            for hour, minute in ((6, 23), (6, 59), (7, 0), (7, 22)):
+                with pinned(2026, 3, 1, hour, minute):
+                    self.aqi_tick(display)
+        display.fetch_air_quality.assert_called_once_with()
+
+
+if __name__ == '__main__':
+    unittest.main()
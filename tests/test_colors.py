@@ -0,0 +1,48 @@
+import itertools
+import math
+import unittest
+
+import weather_display as wd
+
+EXTREME_AMOUNTS = (-100, -1, -0.01, 0, 0.5, 1, 1.01, 2, 100, math.inf, -math.inf)
+CORNERS = list(itertools.product((0, 255), repeat=3))
+
+
+class BlendRgbTest(unittest.TestCase):
+    def assertChannels(self, color):
+        self.assertEqual(len(color), 3)
+        for channel in color:
+            self.assertIsInstance(channel, int)
+            self.assertTrue(0 <= channel <= 255, color)
+
+    def test_channels_stay_in_range_for_any_amount(self):
+        for color, target in itertools.product(CORNERS, repeat=2):
+            for amount in EXTREME_AMOUNTS:
+                with self.subTest(color=color, target=target, amount=amount):
+                    self.assertChannels(wd.blend_rgb(color, target, amount))
+
+    def test_amount_is_clamped(self):
+        self.assertEqual(wd.blend_rgb((10, 20, 30), (200, 100, 0), -5), (10, 20, 30))
+        self.assertEqual(wd.blend_rgb((10, 20, 30), (200, 100, 0), 5), (200, 100, 0))
+        self.assertEqual(wd.blend_rgb((0, 0, 0), (200, 100, 50), 0.5), (100, 50, 25))
+
+
+class TemperatureColorTest(unittest.TestCase):
+    def test_extreme_temperatures_stay_on_the_ramp(self):
+        for temp in (-273.15, -100, -10, 0, 12.5, 35, 60, 1000, math.inf, -math.inf):
+            with self.subTest(temp=temp):
+                color = wd.temperature_to_color(temp, -10, 35)
+                self.assertRegex(color, r'^#[0-9a-f]{6}$')
+                channels = [int(color[i:i + 2], 16) for i in (1, 3, 5)]
+                self.assertTrue(all(0 <= c <= 255 for c in channels), color)
+
+    def test_ends_of_the_ramp(self):
+        self.assertEqual(wd.temperature_to_color(-50, -10, 35), '#%02x%02x%02x' % wd.TEMP_COLOR_COLD)
+        self.assertEqual(wd.temperature_to_color(50, -10, 35), '#%02x%02x%02x' % wd.TEMP_COLOR_HOT)
+
+    def test_empty_range_is_white(self):
+        self.assertEqual(wd.temperature_to_color(20, 10, 10), '#FFFFFF')
+
+
+if __name__ == '__main__':
+    unittest.main()
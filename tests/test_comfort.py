@@ -0,0 +1,54 @@
+import math
+import unittest
+
+import weather_display as wd
+
+
+class DewPointTest(unittest.TestCase):
+    def test_saturated_air_is_at_its_dew_point(self):
+        for temp in (-10, 0, 16, 25, 35):
+            with self.subTest(temp=temp):
+                self.assertAlmostEqual(wd.dew_point(temp, 100), temp, places=6)
+
+    def test_known_value(self):
+        # 20 °C at 50% RH is about 9.3 °C
+        self.assertAlmostEqual(wd.dew_point(20, 50), 9.26, places=1)
+
+    def test_zero_humidity_stays_finite(self):
+        value = wd.dew_point(20, 0)
+        self.assertTrue(math.isfinite(value))
+        self.assertLess(value, -30)
+
+    def test_rises_with_humidity(self):
+        values = [wd.dew_point(25, humidity) for humidity in range(0, 101, 5)]
+        self.assertEqual(values, sorted(values))
+
+
+class ComfortLevelTest(unittest.TestCase):
+    def muggy_cutoff(self, temp):
+        """Lowest whole humidity whose dew point reaches MUGGY_DEW_POINT at temp"""
+        return next(h for h in range(101) if wd.dew_point(temp, h) >= wd.MUGGY_DEW_POINT)
+
+    def test_muggy_cutoff(self):
+        cutoff = self.muggy_cutoff(25)
+        self.assertEqual(wd.comfort_level(25, cutoff), 'Muggy')
+        self.assertEqual(wd.comfort_level(25, cutoff - 1), 'Comfortable')
+
+    def test_humidity_extremes(self):
+        self.assertEqual(wd.comfort_level(25, 100), 'Muggy')
+        self.assertEqual(wd.comfort_level(10, 100), 'Comfortable')  # saturated but cool
+        self.assertEqual(wd.comfort_level(25, 0), 'Dry')
+        self.assertEqual(wd.comfort_level(-5, 0), 'Dry')
+
+    def test_dry_versus_comfortable(self):
+        low = wd.HUMIDITY_COMFORT_MIN
+        self.assertEqual(wd.comfort_level(20, low - 1), 'Dry')
+        self.assertEqual(wd.comfort_level(20, low), 'Comfortable')
+
+    def test_muggy_wins_over_dry(self):
+        # Hot enough that the dew point passes the cutoff below HUMIDITY_COMFORT_MIN
+        self.assertEqual(wd.comfort_level(45, wd.HUMIDITY_COMFORT_MIN - 1), 'Muggy')
+
+
+if __name__ == '__main__':
+    unittest.main()
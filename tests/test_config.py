@@ -0,0 +1,173 @@
+import os
+import unittest
+from contextlib import redirect_stdout
+from io import StringIO
+from unittest import mock
+
+import weather_display as wd
+
+
+def env(**values):
+    return mock.patch.dict(os.environ, values)
+
+
+class EnvNumberTest(unittest.TestCase):
+    def setUp(self):
+        # Keep the [Config] warnings out of the test output
+        patcher = redirect_stdout(StringIO())
+        self.log = patcher.__enter__()
+        self.addCleanup(patcher.__exit__, None, None, None)
+
+    def test_unset_gives_default(self):
+        with mock.patch.dict(os.environ):
+            os.environ.pop('TEST_NUMBER', None)
+            self.assertEqual(wd.env_number('TEST_NUMBER', 7), 7)
+
+    def test_empty_gives_default(self):
+        with env(TEST_NUMBER='  '):
+            self.assertEqual(wd.env_number('TEST_NUMBER', 7), 7)
+
+    def test_valid_values(self):
+        with env(TEST_NUMBER=' 42 '):
+            self.assertEqual(wd.env_number('TEST_NUMBER', 7), 42)
+        with env(TEST_NUMBER='1.5'):
+            self.assertEqual(wd.env_number('TEST_NUMBER', 1.0, float), 1.5)
+
+    def test_non_numeric_gives_default_and_logs(self):
+        with env(TEST_NUMBER='ten'):
+            self.assertEqual(wd.env_number('TEST_NUMBER', 7), 7)
+        self.assertIn("Invalid TEST_NUMBER='ten'", self.log.getvalue())
+
+    def test_float_for_int_setting_is_invalid(self):
+        with env(TEST_NUMBER='2.5'):
+            self.assertEqual(wd.env_number('TEST_NUMBER', 7), 7)
+
+    def test_out_of_range_is_clamped(self):
+        with env(TEST_NUMBER='0'):
+            self.assertEqual(wd.env_number('TEST_NUMBER', 3, low=1, high=7), 1)
+        with env(TEST_NUMBER='99'):
+            self.assertEqual(wd.env_number('TEST_NUMBER', 3, low=1, high=7), 7)
+        with env(TEST_NUMBER='5'):
+            self.assertEqual(wd.env_number('TEST_NUMBER', 3, low=1, high=7), 5)
+
+    def test_default_is_clamped_too(self):
+        with env(TEST_NUMBER='x'):
+            self.assertEqual(wd.env_number('TEST_NUMBER', 10, low=1, high=7), 7)
+
+
+class EnvChoiceTest(unittest.TestCase):
+    CHOICES = ('metric', 'imperial', 'kelvin')
+
+    def test_unset_gives_default(self):
+        with mock.patch.dict(os.environ):
+            os.environ.pop('TEST_CHOICE', None)
+            self.assertEqual(wd.env_choice('TEST_CHOICE', self.CHOICES, 'metric'), 'metric')
+
+    def test_case_and_whitespace_are_ignored(self):
+        with env(TEST_CHOICE=' Imperial '):
+            self.assertEqual(wd.env_choice('TEST_CHOICE', self.CHOICES, 'metric'), 'imperial')
+
+    def test_unknown_choice_gives_default_and_logs(self):
+        log = StringIO()
+        with env(TEST_CHOICE='rankine'), redirect_stdout(log):
+            self.assertEqual(wd.env_choice('TEST_CHOICE', self.CHOICES, 'metric'), 'metric')
+        self.assertIn("Unknown TEST_CHOICE='rankine'", log.getvalue())
+        self.assertIn('metric, imperial, kelvin', log.getvalue())
+
+
+class ParseBoolTest(unittest.TestCase):
+    def test_values(self):
+        for value in ('1', 'true', 'TRUE', ' yes ', 'on'):
+            self.assertTrue(wd.parse_bool(value), value)
+        for value in (None, '', '0', 'false', 'off', 'nope'):
+            self.assertFalse(wd.parse_bool(value), value)
+
+
+class ConfigFromEnvTest(unittest.TestCase):
+    def from_env(self, **values):
+        log = StringIO()
+        with mock.patch.dict(os.environ, values, clear=True), redirect_stdout(log):
+            config = wd.Config.from_env()
+        return config, log.getvalue()
+
+    def test_defaults(self):
+        config, log = self.from_env()
+        self.assertEqual(log, '')
+        self.assertEqual(config.forecast_days, 3)
+        self.assertEqual(config.weather_units, 'metric')
+        self.assertEqual(config.aqi_providers, ['airly', 'open-meteo'])
+        self.assertEqual(config.aqi_bands, wd.DEFAULT_AQI_BANDS)
+        self.assertEqual(config.aqi_bar_max, wd.DEFAULT_AQI_BANDS.high)
+        self.assertEqual(config.phase_brightness, dict.fromkeys(wd.TIME_PHASES, 100))
+        self.assertIsNone(config.quiet_hours)
+        self.assertIsNone(config.fixed_gradient)
+        self.assertTrue(config.air_quality_enabled)
+        self.assertTrue(config.show_transport)
+        self.assertTrue(config.user_agent.startswith('pi-weather/'))
+
+    def test_values_are_parsed(self):
+        config, _ = self.from_env(FORECAST_DAYS='5', WEATHER_UNITS='Imperial', SHOW_WIND='yes',
+                                  QUIET_HOURS='23:00-05:30', AQI_PROVIDERS=' Open-Meteo ,bogus',
+                                  OPEN_METEO_URL='http://localhost:8080/', BRIGHTNESS_NIGHT='20')
+        self.assertEqual(config.forecast_days, 5)
+        self.assertEqual(config.weather_units, 'imperial')
+        self.assertTrue(config.show_wind)
+        self.assertEqual(config.quiet_hours, (23 * 60, 5 * 60 + 30))
+        self.assertEqual(config.aqi_providers, ['open-meteo'])
+        self.assertEqual(config.open_meteo_url, 'http://localhost:8080')
+        self.assertEqual(config.phase_brightness['night'], 20)
+
+    def test_forecast_days_is_clamped(self):
+        self.assertEqual(self.from_env(FORECAST_DAYS='0')[0].forecast_days, 1)
+        self.assertEqual(self.from_env(FORECAST_DAYS='10')[0].forecast_days, 7)
+
+    def test_gps_refresh_interval_has_a_floor(self):
+        self.assertEqual(self.from_env(GPS_REFRESH_INTERVAL='0')[0].gps_refresh_interval, 60)
+        self.assertEqual(self.from_env(GPS_REFRESH_INTERVAL='300')[0].gps_refresh_interval, 300)
+
+    def test_indoor_sensor_interval_has_a_floor(self):
+        self.assertEqual(self.from_env(INDOOR_SENSOR_INTERVAL='0')[0].indoor_sensor_interval, 10)
+        self.assertEqual(self.from_env(INDOOR_SENSOR_INTERVAL='30')[0].indoor_sensor_interval, 30)
+
+    def test_invalid_values_fall_back_to_defaults(self):
+        config, log = self.from_env(FORECAST_DAYS='many', WEATHER_UNITS='rankine', ICON_SCALE='-2',
+                                    QUIET_HOURS='late', AQI_BANDS='1,2,3', DEFAULT_LOCATION='north',
+                                    FIXED_GRADIENT='red,blue')
+        self.assertEqual(config.forecast_days, 3)
+        self.assertEqual(config.weather_units, 'metric')
+        self.assertEqual(config.icon_scale, 1.0)
+        self.assertIsNone(config.quiet_hours)
+        self.assertEqual(config.aqi_bands, wd.DEFAULT_AQI_BANDS)
+        self.assertIsNone(config.default_location)
+        self.assertIsNone(config.fixed_gradient)
+        for name in ('FORECAST_DAYS', 'WEATHER_UNITS', 'ICON_SCALE', 'QUIET_HOURS', 'AQI_BANDS',
+                     'DEFAULT_LOCATION', 'FIXED_GRADIENT'):
+            self.assertIn(name, log)
+
+    def test_aqi_bar_max_follows_the_bands(self):
+        config, _ = self.from_env(AQI_BANDS='10,20,30,40')
+        self.assertEqual(config.aqi_bar_max, 40)
+        config, _ = self.from_env(AQI_BANDS='10,20,30,40', AQI_BAR_MAX='0')
+        self.assertEqual(config.aqi_bar_max, 40)
+
+    def test_compact_layout_turns_off_full_only_sections(self):
+        config, _ = self.from_env(LAYOUT_MODE='compact', SHOW_WIND='true', SHOW_HOURLY='true',
+                                  FORECAST_TIME='08:00', SPARKLINE_POINTS='24', SHOW_CLOCK='true')
+        self.assertFalse(config.air_quality_enabled)
+        self.assertFalse(config.show_wind)
+        self.assertFalse(config.show_hourly)
+        self.assertFalse(config.show_transport)
+        self.assertIsNone(config.forecast_time)
+        self.assertEqual(config.sparkline_points, 0)
+        self.assertTrue(config.show_clock)
+
+    def test_mono_display_drops_color_cues(self):
+        config, _ = self.from_env(DISPLAY_MODE='mono', TEMP_COLOR_SCALE='true', HEAT_TINT='true',
+                                  FIXED_GRADIENT='#112233,#445566')
+        self.assertFalse(config.temp_color_scale)
+        self.assertFalse(config.heat_tint)
+        self.assertEqual(config.fixed_gradient, (wd.MONO_BACKGROUND, wd.MONO_BACKGROUND))
+
+
+if __name__ == '__main__':
+    unittest.main()
@@ -0,0 +1,71 @@
+import unittest
+from datetime import datetime, timedelta, timezone
+
+import weather_display as wd
+
+
+def hourly_data(start='2026-03-01T00:00', hours=48, **extra):
+    first = datetime.fromisoformat(start)
+    times = [(first + timedelta(hours=i)).strftime('%Y-%m-%dT%H:%M') for i in range(hours)]
+    return {'hourly': {'time': times, 'temperature_2m': [float(i) for i in range(hours)]}, **extra}
+
+
+class CurrentHourIndexTest(unittest.TestCase):
+    def test_rows_starting_at_local_midnight(self):
+        data = hourly_data(current={'time': '2026-03-01T14:15'})
+        self.assertEqual(wd.current_hour_index(data, datetime(2026, 3, 1, 14, 15)), 14)
+
+    def test_next_day_rows(self):
+        data = hourly_data(current={'time': '2026-03-02T03:00'})
+        self.assertEqual(wd.current_hour_index(data, datetime(2026, 3, 2, 3, 0)), 27)
+
+    def test_missing_current_time_uses_now(self):
+        data = hourly_data()
+        self.assertEqual(wd.current_hour_index(data, datetime(2026, 3, 1, 9, 59)), 9)
+        data = hourly_data(current={})
+        self.assertEqual(wd.current_hour_index(data, datetime(2026, 3, 1, 23, 0)), 23)
+
+    def test_timezone_offset_of_the_location(self):
+        # Device clock in UTC, location four hours ahead
+        now = datetime(2026, 3, 1, 10, 30, tzinfo=timezone.utc)
+        data = hourly_data(utc_offset_seconds=4 * 3600, current={'time': '2026-03-01T14:00'})
+        self.assertEqual(wd.current_hour_index(data, now), 14)
+        # Negative offset crossing back into the previous day
+        now = datetime(2026, 3, 2, 2, 0, tzinfo=timezone.utc)
+        data = hourly_data(utc_offset_seconds=-5 * 3600)
+        self.assertEqual(wd.current_hour_index(data, now), 21)
+
+    def test_offset_follows_the_clock_after_the_fetch(self):
+        # current.time is the fetch time; an hour later the row moves on
+        data = hourly_data(utc_offset_seconds=0, current={'time': '2026-03-01T14:50'})
+        now = datetime(2026, 3, 1, 15, 5, tzinfo=timezone.utc)
+        self.assertEqual(wd.current_hour_index(data, now), 15)
+
+    def test_hour_outside_the_rows(self):
+        self.assertIsNone(wd.current_hour_index(hourly_data(hours=6), datetime(2026, 3, 1, 12, 0)))
+        self.assertIsNone(wd.current_hour_index({}, datetime(2026, 3, 1, 12, 0)))
+
+
+class HourlyStripTest(unittest.TestCase):
+    def test_starts_at_the_current_hour(self):
+        data = hourly_data(current={'time': '2026-03-01T14:15'})
+        strip = wd.hourly_strip(data, datetime(2026, 3, 1, 14, 15), 4)
+        self.assertEqual([(t.hour, temp, now) for t, temp, now in strip],
+                         [(14, 14.0, True), (15, 15.0, False), (16, 16.0, False), (17, 17.0, False)])
+
+    def test_cut_at_the_end_of_the_rows(self):
+        data = hourly_data(hours=24, current={'time': '2026-03-01T22:00'})
+        self.assertEqual(len(wd.hourly_strip(data, datetime(2026, 3, 1, 22, 0), 6)), 2)
+
+    def test_missing_temperatures_are_left_out(self):
+        data = hourly_data(current={'time': '2026-03-01T10:00'})
+        data['hourly']['temperature_2m'][11] = None
+        strip = wd.hourly_strip(data, datetime(2026, 3, 1, 10, 0), 3)
+        self.assertEqual([t.hour for t, _, _ in strip], [10, 12])
+
+    def test_no_hourly_data(self):
+        self.assertEqual(wd.hourly_strip({}, datetime(2026, 3, 1, 10, 0), 6), [])
+
+
+if __name__ == '__main__':
+    unittest.main()
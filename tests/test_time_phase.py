@@ -0,0 +1,30 @@
+import unittest
+
+import weather_display as wd
+
+
+class FixedTimePhaseTest(unittest.TestCase):
+    EXPECTED = {
+        **{hour: 'night' for hour in (21, 22, 23, 0, 1, 2, 3, 4)},
+        **{hour: 'sunrise' for hour in (5, 6, 7)},
+        **{hour: 'day' for hour in range(8, 17)},
+        **{hour: 'sunset' for hour in (17, 18, 19, 20)},
+    }
+
+    def test_every_hour(self):
+        self.assertEqual(sorted(self.EXPECTED), list(range(24)))
+        for hour, phase in self.EXPECTED.items():
+            with self.subTest(hour=hour):
+                self.assertEqual(wd.fixed_time_phase(hour), phase)
+
+    def test_boundaries(self):
+        for before, after, phases in ((4, 5, ('night', 'sunrise')),
+                                      (7, 8, ('sunrise', 'day')),
+                                      (16, 17, ('day', 'sunset')),
+                                      (20, 21, ('sunset', 'night'))):
+            with self.subTest(boundary=f"{before}/{after}"):
+                self.assertEqual((wd.fixed_time_phase(before), wd.fixed_time_phase(after)), phases)
+
+
+if __name__ == '__main__':
+    unittest.main()
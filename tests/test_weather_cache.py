@@ -0,0 +1,67 @@
+"""A weather response only counts as a success (and is cached) once the display could show it"""
+
+import unittest
+from contextlib import redirect_stdout
+from datetime import datetime
+from io import StringIO
+from unittest import mock
+
+import weather_display as wd
+
+
+class ApplyWeatherTest(unittest.TestCase):
+    def apply(self, shown):
+        display = mock.Mock(weather_failures=2, weather_degraded=True, weather_live=False)
+        display.update_weather_display.return_value = shown
+        with redirect_stdout(StringIO()):
+            wd.WeatherDisplay.apply_weather(display, {'current': {'temperature_2m': 12}}, 'W1')
+        return display
+
+    def test_shown_response_resets_the_failures_and_is_cached(self):
+        display = self.apply(shown=True)
+        self.assertEqual(display.weather_failures, 0)
+        self.assertFalse(display.weather_degraded)
+        self.assertTrue(display.weather_live)
+        display.save_weather_cache.assert_called_once()
+        display.weather_fetch_failed.assert_not_called()
+
+    def test_unusable_response_counts_as_a_failed_fetch(self):
+        display = self.apply(shown=False)
+        self.assertEqual(display.weather_failures, 2)
+        self.assertTrue(display.weather_degraded)
+        self.assertFalse(display.weather_live)
+        display.save_weather_cache.assert_not_called()
+        display.weather_fetch_failed.assert_called_once()
+
+    def test_response_without_current_is_not_shown(self):
+        with redirect_stdout(StringIO()):
+            self.assertFalse(wd.WeatherDisplay.update_weather_display(mock.Mock(), {'daily': {}}))
+
+
+
+class CachedReplayTest(unittest.TestCase):
+    DATA = {'current': {'temperature_2m': 12.5, 'weather_code': 3, 'pressure_msl': 1013}}
+
+    def show(self, saved_at=None):
+        display = mock.Mock(temperature_history=[])
+        display.current_hour_precipitation.return_value = None
+        with mock.patch.object(wd, 'SPARKLINE_POINTS', 12), \
+                mock.patch.object(wd, 'local_now', return_value=datetime(2026, 3, 1, 9, 0)):
+            self.assertTrue(wd.WeatherDisplay.update_weather_display(display, self.DATA, saved_at=saved_at))
+        return display
+
+    def test_live_response_is_recorded(self):
+        display = self.show()
+        self.assertEqual(display.temperature_history, [12.5])
+        display.update_pressure.assert_called_once_with(self.DATA, record=True)
+        self.assertEqual(display.weather_updated_at, datetime(2026, 3, 1, 9, 0))
+
+    def test_cached_replay_keeps_the_histories_and_its_age(self):
+        saved_at = datetime(2026, 3, 1, 7, 30)
+        display = self.show(saved_at)
+        self.assertEqual(display.temperature_history, [])
+        display.update_pressure.assert_called_once_with(self.DATA, record=False)
+        self.assertEqual(display.weather_updated_at, saved_at)
+
+if __name__ == '__main__':
+    unittest.main()
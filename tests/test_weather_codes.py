@@ -0,0 +1,37 @@
+import unittest
+from contextlib import redirect_stdout
+from io import StringIO
+from unittest import mock
+
+import weather_display as wd
+
+# Every WMO code Open-Meteo documents for weather_code
+DOCUMENTED_CODES = {0, 1, 2, 3, 45, 48, 51, 53, 55, 56, 57, 61, 63, 65, 66, 67,
+                    71, 73, 75, 77, 80, 81, 82, 85, 86, 95, 96, 99}
+
+
+class WeatherCodesTest(unittest.TestCase):
+    def test_every_documented_code_has_a_description(self):
+        self.assertEqual(set(wd.WEATHER_CODES), DOCUMENTED_CODES)
+        for code in DOCUMENTED_CODES:
+            with self.subTest(code=code):
+                description = wd.weather_description(code)
+                self.assertTrue(description)
+                self.assertNotEqual(description, wd.UNKNOWN_CONDITION_TEXT)
+
+    def test_unknown_code_falls_back_and_logs_once(self):
+        log = StringIO()
+        with mock.patch.object(wd, '_reported_unknown_codes', set()), redirect_stdout(log):
+            self.assertEqual(wd.weather_description(42), wd.UNKNOWN_CONDITION_TEXT)
+            self.assertEqual(wd.weather_description(42), wd.UNKNOWN_CONDITION_TEXT)
+            self.assertEqual(wd.weather_description(None), wd.UNKNOWN_CONDITION_TEXT)
+        self.assertEqual(log.getvalue().count('Unmapped weather code 42'), 1)
+        self.assertEqual(log.getvalue().count('Unmapped weather code None'), 1)
+
+    def test_fallback_text_is_configurable(self):
+        with mock.patch.object(wd, 'UNKNOWN_CONDITION_TEXT', '?'), redirect_stdout(StringIO()):
+            self.assertEqual(wd.weather_description(100), '?')
+
+
+if __name__ == '__main__':
+    unittest.main()
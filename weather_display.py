@@ -7,26 +7,571 @@ Lightweight weather display for Raspberry Pi
 import tkinter as tk
 from tkinter import font
 import requests
-from datetime import datetime
+from datetime import datetime, timedelta, timezone
+from collections import deque, namedtuple
+from dataclasses import asdict, dataclass, replace
 import time
 import os
+import argparse
+import json
+import math
+import random
+import socket
+import threading
+import traceback
+import queue
 from dotenv import load_dotenv
 from PIL import Image, ImageTk
 from io import BytesIO
+from urllib.parse import urlparse
 from urllib.request import urlopen
+from embedded_assets import embedded_asset
 
 # Load environment variables FIRST (before using os.getenv)
 load_dotenv()
 
+APP_VERSION = '1.0'
+
+def parse_bool(value):
+    return str(value).strip().lower() in ('1', 'true', 'yes', 'on')
+
+def env_number(name, default, cast=int, low=None, high=None):
+    """Numeric setting: unset/empty gives default, unparseable gives default (logged), then clamped to low..high"""
+    raw = os.getenv(name, '').strip()
+    value = default
+    if raw:
+        try:
+            value = cast(raw)
+        except ValueError:
+            print(f"[Config] Invalid {name}={raw!r}, using {default}")
+    if low is not None:
+        value = max(low, value)
+    if high is not None:
+        value = min(high, value)
+    return value
+
+def env_choice(name, choices, default):
+    """One of choices (case-insensitive); unknown values are logged and replaced by default"""
+    value = os.getenv(name, default).strip().lower()
+    if value not in choices:
+        print(f"[Config] Unknown {name}={value!r}, using {default} (choose from {', '.join(choices)})")
+        return default
+    return value
+
+def local_now():
+    """Current local time. Every time-dependent path reads the clock through here,
+    so tests can pin it (see tests/test_clock.py)."""
+    return datetime.now()
+
+# Assets live next to this script, not the CWD (systemd starts services in /)
+PROJECT_DIR = os.path.dirname(os.path.abspath(__file__))
+
+# Allowed values of the choice settings
+AQI_PROVIDER_NAMES = ('airly', 'open-meteo')
+POLLUTANT_UNITS = ('ugm3', 'percent')
+WEATHER_UNITS_CHOICES = ('metric', 'imperial', 'kelvin')
+PRIMARY_TEMP_CHOICES = ('actual', 'apparent')
+CLOCK_POSITIONS = ('top-left', 'top-right', 'bottom-left', 'bottom-right')
+LAYOUT_MODES = ('full', 'compact')
+DISPLAY_MODES = ('color', 'mono')
+TIME_PHASES = ('night', 'sunrise', 'day', 'sunset')  # Per-phase settings: BRIGHTNESS_<PHASE>
+MONO_BACKGROUND = (0, 0, 0)  # FIXED_GRADIENT in DISPLAY_MODE=mono
+
+def parse_hex_color(value):
+    """'#rrggbb' -> (r, g, b); raises ValueError for anything else"""
+    if not (isinstance(value, str) and len(value) == 7 and value.startswith('#')):
+        raise ValueError(f"expected '#rrggbb', got {value!r}")
+    return int(value[1:3], 16), int(value[3:5], 16), int(value[5:7], 16)
+
+def parse_fixed_gradient(value):
+    """FIXED_GRADIENT="#rrggbb,#rrggbb" (top, bottom) -> (start_rgb, end_rgb), or None when unset/invalid"""
+    if not value.strip():
+        return None
+    try:
+        start, end = (part.strip() for part in value.split(','))
+        gradient = parse_hex_color(start), parse_hex_color(end)
+    except ValueError as e:
+        print(f"[Config] Invalid FIXED_GRADIENT {value!r} ({e}), expected '#rrggbb,#rrggbb'")
+        return None
+    print(f"[Config] Fixed background gradient {start} -> {end} (time and weather ignored)")
+    return gradient
+
+def parse_quiet_hours(value):
+    """Parse "HH:MM-HH:MM" into (start, end) minutes since midnight, or None"""
+    if not value.strip():
+        return None
+    try:
+        start, end = value.split('-')
+        bounds = []
+        for part in (start, end):
+            hours, minutes = part.strip().split(':')
+            bounds.append(int(hours) * 60 + int(minutes))
+        return tuple(bounds)
+    except ValueError:
+        print(f"[Config] Invalid QUIET_HOURS '{value}', expected HH:MM-HH:MM")
+        return None
+
+def parse_forecast_time(value):
+    """Parse "HH:MM" into (hour, minute), or None when empty or invalid"""
+    if not value.strip():
+        return None
+    try:
+        hours, minutes = (int(part) for part in value.strip().split(':'))
+        if 0 <= hours < 24 and 0 <= minutes < 60:
+            return hours, minutes
+    except ValueError:
+        pass
+    print(f"[Config] Invalid FORECAST_TIME '{value}', expected HH:MM")
+    return None
+
+# Upper CAQI bound of each status band; the last band is open-ended ("Hazardous")
+AqiBands = namedtuple('AqiBands', 'very_low low medium high')
+DEFAULT_AQI_BANDS = AqiBands(33, 66, 99, 150)
+
+def parse_aqi_bands(value):
+    """AQI_BANDS="33,66,99,150" -> AqiBands; defaults when unset, malformed or not strictly increasing"""
+    if not value.strip():
+        return DEFAULT_AQI_BANDS
+    try:
+        bands = AqiBands(*(float(part) for part in value.split(',')))
+    except (TypeError, ValueError):
+        print(f"[Config] AQI_BANDS={value!r} needs 4 numbers, using {','.join(map(str, DEFAULT_AQI_BANDS))}")
+        return DEFAULT_AQI_BANDS
+    if any(lower >= upper for lower, upper in zip(bands, bands[1:])):
+        print(f"[Config] AQI_BANDS={value!r} is not strictly increasing, using defaults")
+        return DEFAULT_AQI_BANDS
+    return bands
+
+def parse_default_location(value):
+    """Parse DEFAULT_LOCATION ("lat,lon") into a tuple, or None when unset/invalid"""
+    if not value.strip():
+        return None
+    try:
+        lat, lon = (float(part) for part in value.split(','))
+    except ValueError:
+        print(f"[Config] Invalid DEFAULT_LOCATION '{value}', expected 'lat,lon'")
+        return None
+    if not (-90 <= lat <= 90 and -180 <= lon <= 180):
+        print(f"[Config] DEFAULT_LOCATION '{value}' is out of range")
+        return None
+    return lat, lon
+
+@dataclass(frozen=True)
+class Config:
+    """Every environment setting, parsed, defaulted and validated in one place
+
+    Built once at import by from_env() into CONFIG; the module-level constants
+    are aliases of its fields. Invalid values are logged with a [Config] prefix
+    and replaced by their defaults. LAYOUT_MODE=compact and DISPLAY_MODE=mono
+    switch off the settings they don't support here, not at the use sites.
+    """
+    assets_dir: str = None
+    icon_set: str = ''
+    icon_scale: float = 1.0
+    cache_dir: str = None
+    gradients_file: str = None
+    fixed_gradient: tuple = None  # (start_rgb, end_rgb)
+    fetch_jitter: int = 20
+    fetch_spread: bool = True
+    fetch_cache_ttl: int = 30
+    resume_gap_threshold: int = 300
+    sparkline_points: int = 12
+    background_fetch: bool = False
+    show_sun_countdown: bool = True
+    weather_stale_minutes: int = 90
+    aqi_stale_minutes: int = 720
+    cache_max_age_minutes: int = 360
+    fetch_failure_threshold: int = 3
+    ui_recovery_attempts: int = 3
+    user_agent: str = None
+    transport_station_id: str = '900003201'
+    airly_api_key: str = None
+    airly_latitude: str = None
+    airly_longitude: str = None
+    airly_max_distance_km: float = 5.0
+    air_quality_enabled: bool = True
+    aqi_providers: list = None
+    airly_installation_id: str = ''
+    airly_installation_name: str = ''
+    airly_index_priority: list = None
+    debug: bool = False
+    aqi_daytime_only: bool = False
+    aqi_simple: bool = False
+    show_aqi_number: bool = False
+    aqi_number_decimals: int = 0
+    show_aqi_norm: bool = False
+    show_pollutants: bool = False
+    pollutant_unit: str = 'ugm3'
+    pollutant_decimals: int = 0
+    quiet_hours: tuple = None  # (start, end) minutes since midnight
+    open_meteo_url: str = 'https://api.open-meteo.com'
+    open_meteo_air_quality_url: str = 'https://air-quality-api.open-meteo.com'
+    open_meteo_api_key: str = ''
+    open_meteo_backup_url: str = ''
+    reconnect_refetch: bool = True
+    reconnect_debounce: int = 300
+    sd_notify: bool = True
+    number_locale: str = 'en'
+    text_max_chars: int = 48
+    show_wind: bool = False
+    show_humidity: bool = False
+    show_only_relevant: bool = False
+    wind_relevant_kmh: float = 20.0
+    humidity_comfort_min: float = 30.0
+    humidity_comfort_max: float = 70.0
+    show_comfort: bool = False
+    condition_show_precip: bool = True
+    show_pressure: bool = False
+    show_next_rain: bool = False
+    rain_probability_threshold: int = 50
+    forecast_time: tuple = None  # (hour, minute)
+    show_hourly: bool = False
+    hourly_hours: int = 6
+    show_attribution: bool = True
+    show_fetch_indicator: bool = True
+    show_summary: bool = False
+    show_pollen: bool = False
+    forecast_days: int = 3
+    heat_tint: bool = False
+    heat_tint_threshold: float = 35.0
+    weather_units: str = 'metric'
+    temp_show_unit: bool = False
+    temp_unit_space: bool = False
+    primary_temp: str = 'actual'
+    temp_color_scale: bool = False
+    temp_color_min: float = -10.0
+    temp_color_max: float = 35.0
+    gpsd_addr: str = ''
+    gps_refresh_interval: int = 900
+    relocate_distance_km: float = 5.0
+    aqi_bands: AqiBands = DEFAULT_AQI_BANDS
+    aqi_bar_max: float = None
+    alert_gpio_pin: str = ''
+    aqi_rise_delta: float = 30.0
+    aqi_rise_minutes: int = 60
+    phase_brightness: dict = None  # Percent per time phase
+    indoor_sensor: str = ''
+    indoor_sensor_interval: int = 120
+    show_clock: bool = True
+    clock_position: str = 'top-left'
+    show_health: bool = False
+    night_face: bool = False
+    night_face_wake_seconds: int = 30
+    location_city: str = 'Berlin'
+    location_country: str = 'Germany'
+    default_location: tuple = None  # (lat, lon)
+    coord_precision: int = 4
+    show_location: bool = True
+    unknown_condition_text: str = 'Unknown'
+    phase_from_sun: bool = False
+    layout_mode: str = 'full'
+    display_mode: str = 'color'
+
+    @property
+    def show_transport(self):
+        return self.layout_mode == 'full'
+
+    @classmethod
+    def from_env(cls):
+        """Parse the current environment (after load_dotenv) into a Config"""
+        layout_mode = env_choice('LAYOUT_MODE', LAYOUT_MODES, 'full')
+        display_mode = env_choice('DISPLAY_MODE', DISPLAY_MODES, 'color')
+        compact = layout_mode == 'compact'
+        mono = display_mode == 'mono'
+
+        def flag(name, default, full_only=False):
+            """Boolean setting; full_only sections are always off in the compact layout"""
+            return parse_bool(os.getenv(name, default)) and not (full_only and compact)
+
+        def names(name, default):
+            """Comma-separated list setting, stripped, empty entries dropped"""
+            return [part.strip() for part in os.getenv(name, default).split(',') if part.strip()]
+
+        def url(name, default=''):
+            return os.getenv(name, default).strip().rstrip('/')
+
+        icon_scale = env_number('ICON_SCALE', 1.0, float)
+        if icon_scale <= 0:
+            print("[Config] ICON_SCALE must be positive, using 1.0")
+            icon_scale = 1.0
+
+        aqi_bands = parse_aqi_bands(os.getenv('AQI_BANDS', ''))
+        aqi_bar_max = env_number('AQI_BAR_MAX', float(aqi_bands.high), float)
+        if aqi_bar_max <= 0:
+            print(f"[Config] AQI_BAR_MAX must be positive, using {aqi_bands.high}")
+            aqi_bar_max = aqi_bands.high
+
+        fixed_gradient = parse_fixed_gradient(os.getenv('FIXED_GRADIENT', ''))
+        if mono:
+            fixed_gradient = (MONO_BACKGROUND, MONO_BACKGROUND)
+
+        return cls(
+            assets_dir=os.getenv('ASSETS_DIR') or os.path.join(PROJECT_DIR, 'assets'),
+            icon_set=os.getenv('ICON_SET', '').strip(),
+            icon_scale=icon_scale,
+            cache_dir=os.path.join(os.getenv('XDG_CACHE_HOME') or os.path.expanduser('~/.cache'), 'pi-weather'),
+            gradients_file=os.getenv('GRADIENTS_FILE') or os.path.join(PROJECT_DIR, 'gradients.toml'),
+            fixed_gradient=fixed_gradient,
+            fetch_jitter=env_number('FETCH_JITTER', 20, low=0),
+            fetch_spread=flag('FETCH_SPREAD', 'true'),
+            fetch_cache_ttl=env_number('FETCH_CACHE_TTL', 30),
+            resume_gap_threshold=env_number('RESUME_GAP_THRESHOLD', 300),
+            sparkline_points=0 if compact else env_number('SPARKLINE_POINTS', 12),
+            background_fetch=flag('BACKGROUND_FETCH', 'false'),
+            show_sun_countdown=flag('SHOW_SUN_COUNTDOWN', 'true', full_only=True),
+            weather_stale_minutes=env_number('WEATHER_STALE_MINUTES', 90),
+            aqi_stale_minutes=env_number('AQI_STALE_MINUTES', 720),
+            cache_max_age_minutes=env_number('CACHE_MAX_AGE_MINUTES', 360),
+            fetch_failure_threshold=env_number('FETCH_FAILURE_THRESHOLD', 3, low=1),
+            ui_recovery_attempts=env_number('UI_RECOVERY_ATTEMPTS', 3),
+            user_agent=os.getenv('USER_AGENT', '').strip() or f"pi-weather/{APP_VERSION}",
+            transport_station_id=os.getenv('TRANSPORT_STATION_ID', '900003201'),
+            airly_api_key=os.getenv('AIRLY_API_KEY'),
+            airly_latitude=os.getenv('AIRLY_LATITUDE'),
+            airly_longitude=os.getenv('AIRLY_LONGITUDE'),
+            airly_max_distance_km=env_number('AIRLY_MAX_DISTANCE_KM', 5.0, float, low=0.0),
+            air_quality_enabled=flag('AIR_QUALITY', 'true', full_only=True),
+            aqi_providers=[name for name in (part.lower() for part in names('AQI_PROVIDERS', 'airly,open-meteo'))
+                           if name in AQI_PROVIDER_NAMES] or list(AQI_PROVIDER_NAMES),
+            airly_installation_id=os.getenv('AIRLY_INSTALLATION_ID', '').strip(),
+            airly_installation_name=os.getenv('AIRLY_INSTALLATION_NAME', '').strip(),
+            airly_index_priority=[name.upper() for name in names('AIRLY_INDEX_PRIORITY', 'AIRLY_CAQI,PM25')],
+            debug=flag('DEBUG', 'false'),
+            aqi_daytime_only=flag('AQI_DAYTIME_ONLY', 'false'),
+            aqi_simple=flag('AQI_SIMPLE', 'false'),
+            show_aqi_number=flag('SHOW_AQI_NUMBER', 'false'),
+            aqi_number_decimals=env_number('AQI_NUMBER_DECIMALS', 0, low=0, high=2),
+            show_aqi_norm=flag('SHOW_AQI_NORM', 'false', full_only=True),
+            show_pollutants=flag('SHOW_POLLUTANTS', 'false', full_only=True),
+            pollutant_unit=env_choice('POLLUTANT_UNIT', POLLUTANT_UNITS, 'ugm3'),
+            pollutant_decimals=env_number('POLLUTANT_DECIMALS', 0, low=0, high=2),
+            quiet_hours=parse_quiet_hours(os.getenv('QUIET_HOURS', '')),
+            open_meteo_url=url('OPEN_METEO_URL', 'https://api.open-meteo.com'),
+            open_meteo_air_quality_url=url('OPEN_METEO_AIR_QUALITY_URL', 'https://air-quality-api.open-meteo.com'),
+            open_meteo_api_key=os.getenv('OPEN_METEO_API_KEY', '').strip(),
+            open_meteo_backup_url=url('OPEN_METEO_BACKUP_URL'),
+            reconnect_refetch=flag('RECONNECT_REFETCH', 'true'),
+            reconnect_debounce=env_number('RECONNECT_DEBOUNCE', 300),
+            sd_notify=flag('SD_NOTIFY', 'true'),
+            number_locale=os.getenv('NUMBER_LOCALE', 'en').strip().lower(),
+            text_max_chars=env_number('TEXT_MAX_CHARS', 48, low=8),
+            show_wind=flag('SHOW_WIND', 'false', full_only=True),
+            show_humidity=flag('SHOW_HUMIDITY', 'false', full_only=True),
+            show_only_relevant=flag('SHOW_ONLY_RELEVANT', 'false'),
+            wind_relevant_kmh=env_number('WIND_RELEVANT_KMH', 20.0, float),
+            humidity_comfort_min=env_number('HUMIDITY_COMFORT_MIN', 30.0, float),
+            humidity_comfort_max=env_number('HUMIDITY_COMFORT_MAX', 70.0, float),
+            show_comfort=flag('SHOW_COMFORT', 'false', full_only=True),
+            condition_show_precip=flag('CONDITION_SHOW_PRECIP', 'true'),
+            show_pressure=flag('SHOW_PRESSURE', 'false', full_only=True),
+            show_next_rain=flag('SHOW_NEXT_RAIN', 'false', full_only=True),
+            rain_probability_threshold=env_number('RAIN_PROBABILITY_THRESHOLD', 50),
+            forecast_time=None if compact else parse_forecast_time(os.getenv('FORECAST_TIME', '')),
+            show_hourly=flag('SHOW_HOURLY', 'false', full_only=True),
+            hourly_hours=env_number('HOURLY_HOURS', 6, low=2, high=12),
+            show_attribution=flag('SHOW_ATTRIBUTION', 'true'),
+            show_fetch_indicator=flag('SHOW_FETCH_INDICATOR', 'true'),
+            show_summary=flag('SHOW_SUMMARY', 'false', full_only=True),
+            show_pollen=flag('SHOW_POLLEN', 'false', full_only=True),
+            forecast_days=env_number('FORECAST_DAYS', 3, low=1, high=7),
+            heat_tint=flag('HEAT_TINT', 'false') and not mono,
+            heat_tint_threshold=env_number('HEAT_TINT_THRESHOLD', 35.0, float),
+            weather_units=env_choice('WEATHER_UNITS', WEATHER_UNITS_CHOICES, 'metric'),
+            temp_show_unit=flag('TEMP_SHOW_UNIT', 'false'),
+            temp_unit_space=flag('TEMP_UNIT_SPACE', 'false'),
+            primary_temp=env_choice('PRIMARY_TEMP', PRIMARY_TEMP_CHOICES, 'actual'),
+            temp_color_scale=flag('TEMP_COLOR_SCALE', 'false') and not mono,
+            temp_color_min=env_number('TEMP_COLOR_MIN', -10.0, float),
+            temp_color_max=env_number('TEMP_COLOR_MAX', 35.0, float),
+            gpsd_addr=os.getenv('GPSD_ADDR', '').strip(),
+            gps_refresh_interval=env_number('GPS_REFRESH_INTERVAL', 900, low=60),
+            relocate_distance_km=env_number('RELOCATE_DISTANCE_KM', 5.0, float),
+            aqi_bands=aqi_bands,
+            aqi_bar_max=aqi_bar_max,
+            alert_gpio_pin=os.getenv('ALERT_GPIO_PIN', '').strip(),
+            aqi_rise_delta=env_number('AQI_RISE_DELTA', 30.0, float, low=0.0),
+            aqi_rise_minutes=env_number('AQI_RISE_MINUTES', 60, low=1),
+            phase_brightness={phase: env_number(f'BRIGHTNESS_{phase.upper()}', 100, low=0, high=100)
+                              for phase in TIME_PHASES},
+            indoor_sensor=os.getenv('INDOOR_SENSOR', '').strip().lower(),
+            indoor_sensor_interval=env_number('INDOOR_SENSOR_INTERVAL', 120, low=10),
+            show_clock=flag('SHOW_CLOCK', 'true'),
+            clock_position=env_choice('CLOCK_POSITION', CLOCK_POSITIONS, 'top-left'),
+            show_health=flag('SHOW_HEALTH', 'false'),
+            night_face=flag('NIGHT_FACE', 'false'),
+            night_face_wake_seconds=env_number('NIGHT_FACE_WAKE_SECONDS', 30, low=0),
+            location_city=os.getenv('LOCATION_CITY', 'Berlin'),
+            location_country=os.getenv('LOCATION_COUNTRY', 'Germany'),
+            default_location=parse_default_location(os.getenv('DEFAULT_LOCATION', '')),
+            coord_precision=env_number('COORD_PRECISION', 4, low=0, high=6),
+            show_location=flag('SHOW_LOCATION', 'true', full_only=True),
+            unknown_condition_text=os.getenv('UNKNOWN_CONDITION_TEXT', 'Unknown'),
+            phase_from_sun=flag('PHASE_FROM_SUN', 'false'),
+            layout_mode=layout_mode,
+            display_mode=display_mode,
+        )
+
+CONFIG = Config.from_env()
+
+ASSETS_DIR = CONFIG.assets_dir
+
+# Optional theme directory with the same filenames; missing files fall back to ASSETS_DIR
+ICON_SET = CONFIG.icon_set
+CORE_ASSETS = ('bar_full.png', 'bar_indicator.png')
+# Size factor for the slider indicator icon, independent of the bar (icon packs differ in resolution)
+ICON_SCALE = CONFIG.icon_scale
+
+def asset_path(filename):
+    if ICON_SET:
+        themed = os.path.join(ICON_SET, filename)
+        if os.path.exists(themed):
+            return themed
+    return os.path.join(ASSETS_DIR, filename)
+
+def open_asset(filename):
+    """PIL image of a core asset: the file from asset_path, else the copy in embedded_assets.py"""
+    from PIL import Image
+    path = asset_path(filename)
+    if os.path.exists(path):
+        return Image.open(path)
+    data = embedded_asset(filename)
+    if data is None:
+        raise FileNotFoundError(path)
+    print(f"[Assets] {path} not found, using the embedded {filename}")
+    return Image.open(BytesIO(data))
+
+def validate_icon_set():
+    """Warn at startup when ICON_SET lacks any of the core images"""
+    if not ICON_SET:
+        return
+    if not os.path.isdir(ICON_SET):
+        print(f"[Assets] ICON_SET {ICON_SET} is not a directory, using default assets")
+        return
+    missing = [name for name in CORE_ASSETS if not os.path.exists(os.path.join(ICON_SET, name))]
+    if missing:
+        print(f"[Assets] ICON_SET {ICON_SET} is missing {', '.join(missing)}; defaults used for those")
+    else:
+        print(f"[Assets] Using icon set {ICON_SET}")
+
+# Anything written at runtime goes to the XDG cache, never to ASSETS_DIR (may be read-only)
+CACHE_DIR = CONFIG.cache_dir
+
+def cache_path(filename):
+    """Path of a file in CACHE_DIR, creating the directory on first use"""
+    os.makedirs(CACHE_DIR, exist_ok=True)
+    return os.path.join(CACHE_DIR, filename)
+
+# Optional per-weather-code day gradients, e.g. in gradients.toml:
+#   [codes]
+#   95 = ["#3a1c71", "#1a0b2e"]   # thunderstorm: top, bottom
+GRADIENTS_FILE = CONFIG.gradients_file
+
+def load_gradient_overrides(path):
+    """{weather_code: (start_rgb, end_rgb)} from the [codes] table; {} when absent or unreadable"""
+    if not os.path.exists(path):
+        return {}
+    try:
+        import tomllib  # Python 3.11+
+        with open(path, 'rb') as f:
+            codes = tomllib.load(f).get('codes', {})
+    except Exception as e:
+        print(f"[Config] Could not read {path}: {e}; using default gradients")
+        return {}
+    
+    overrides = {}
+    for code, colors in codes.items():
+        try:
+            start, end = colors
+            overrides[int(code)] = (parse_hex_color(start), parse_hex_color(end))
+        except (TypeError, ValueError) as e:
+            print(f"[Config] Ignoring gradient for code {code!r} in {path}: {e}")
+    if overrides:
+        print(f"[Config] Gradient overrides for weather codes {sorted(overrides)}")
+    return overrides
+
+GRADIENT_OVERRIDES = load_gradient_overrides(GRADIENTS_FILE)
+
+# Constant background that replaces the phase/weather gradients (for a calm, unchanging screen;
+# always black in DISPLAY_MODE=mono)
+FIXED_GRADIENT = CONFIG.fixed_gradient
+
 # Configuration
 REFRESH_INTERVAL = 1800  # Refresh weather every 30 minutes (in seconds)
+# Random 0..FETCH_JITTER s delay before the first fetches, so many displays booting
+# together (power outage) don't hit the free APIs in the same second; 0 = off
+FETCH_JITTER = CONFIG.fetch_jitter
+# Give each display its own random phase within the refresh interval and a random minute within
+# each scheduled AQI hour, so displays started together stay out of step afterwards too
+FETCH_SPREAD = CONFIG.fetch_spread
 TIME_UPDATE_INTERVAL = 59  # Update time every minute (in seconds)
 TRANSPORT_REFRESH_INTERVAL = 360  # Refresh transport API every 6 minutes (in seconds)
 TRANSPORT_DISPLAY_INTERVAL = 60  # Update transport countdown display every minute (in seconds)
+FETCH_CACHE_TTL = CONFIG.fetch_cache_ttl  # Reuse identical fetches within this many seconds (0 = off)
+RESUME_GAP_THRESHOLD = CONFIG.resume_gap_threshold  # Clock-tick gap (s) treated as a suspend
+SPARKLINE_POINTS = CONFIG.sparkline_points  # Recent temperatures in the trend line (0 = off)
+SPARKLINE_SIZE = (160, 30)  # Width, height in pixels
+# Run weather requests on a worker thread so a slow API never freezes the clock
+BACKGROUND_FETCH = CONFIG.background_fetch
+FETCH_POLL_INTERVAL = 200  # ms between checks for finished background fetches
+FETCH_WORKER_JOIN_TIMEOUT = 5  # seconds to wait for an in-flight request on shutdown
+SHOW_SUN_COUNTDOWN = CONFIG.show_sun_countdown  # "Sunset in 2h 14m" under the clock
+WEATHER_STALE_MINUTES = CONFIG.weather_stale_minutes  # Dim weather older than this
+AQI_STALE_MINUTES = CONFIG.aqi_stale_minutes  # Dim AQI older than this (3 fetches/day)
+STALE_COLOR = '#9aa3b5'
+WEATHER_CACHE_FILE = 'weather.json'  # Last good weather response, in CACHE_DIR
+CACHE_MAX_AGE_MINUTES = CONFIG.cache_max_age_minutes  # Older cached weather is not shown
+# Until the first weather arrives, failed fetches are retried after 10, 20, 40, 80 s instead of waiting
+# REFRESH_INTERVAL; the screen shows "Connecting… (attempt N)" meanwhile
+STARTUP_ATTEMPTS = 5
+STARTUP_RETRY_DELAY = 10  # Seconds before the first retry, doubled each time
+FETCH_FAILURE_THRESHOLD = CONFIG.fetch_failure_threshold  # Failed fetches in a row before "unreachable"
+WATCHDOG_FACTOR = 3  # Force a fetch when a timer has been silent for this many intervals
+DEMO_STEP_INTERVAL = 4  # Seconds between simulated conditions in --demo mode
+# Kiosk recovery: a burst of UI callback errors (or a Tk error escaping the main loop) closes
+# the window and builds a new one, at most UI_RECOVERY_ATTEMPTS times before exiting
+UI_RECOVERY_ATTEMPTS = CONFIG.ui_recovery_attempts
+UI_ERROR_BURST = 10  # Callback errors within UI_ERROR_WINDOW seconds that count as a broken UI
+UI_ERROR_WINDOW = 60
+UI_RECOVERY_DELAY = 5  # Seconds to wait before recreating the window
+
+# Sent with every HTTP request so the API providers can identify (and contact) this client
+USER_AGENT = CONFIG.user_agent
+
+def new_http_session():
+    """requests.Session sending USER_AGENT"""
+    session = requests.Session()
+    session.headers['User-Agent'] = USER_AGENT
+    return session
+
+# One session for every Tk-thread request: its connection pool reuses TLS connections to the
+# same API host, which saves a full handshake per fetch on a Pi Zero. Sessions aren't
+# thread-safe, so the background fetch worker has its own.
+HTTP_SESSION = new_http_session()
+
+def new_fetch_id(kind):
+    """Short random ID ("wx-3f2a") prefixed to every log line of one fetch"""
+    return f"{kind}-{random.getrandbits(16):04x}"
+
+def url_without_query(url):
+    """scheme://host/path of a URL, for logs (query strings may carry API keys)"""
+    parts = urlparse(url)
+    return f"{parts.scheme}://{parts.netloc}{parts.path}"
+
+def http_get(url, session=None, **kwargs):
+    """GET through session, HTTP_SESSION by default (USER_AGENT; per-call headers are added on top)
+
+    Redirects are still followed, but logged, so a moved API host shows up
+    in the logs before it turns into failures.
+    """
+    response = (session or HTTP_SESSION).get(url, **kwargs)
+    if response.history:
+        codes = ', '.join(str(hop.status_code) for hop in response.history)
+        print(f"[HTTP] {url_without_query(url)} redirected ({codes}) to {url_without_query(response.url)}")
+    return response
 
 # Transport API configuration (VBB)
 TRANSPORT_API_BASE = "https://v6.vbb.transport.rest/stops"
-TRANSPORT_STATION_ID = os.getenv('TRANSPORT_STATION_ID', '900003201')  # Default: Berlin Hbf
+TRANSPORT_STATION_ID = CONFIG.transport_station_id  # Default: Berlin Hbf
 TRANSPORT_DURATION = 25  # Look ahead duration in minutes
 TRANSPORT_RESULTS = 6  # Number of departures to fetch (enough for ~3 per direction)
 
@@ -44,23 +589,571 @@ def build_transport_url():
         f"&express=false"
         f"&regional=false"
     )
-AIRLY_API_KEY = os.getenv('AIRLY_API_KEY')
-AIRLY_LATITUDE = os.getenv('AIRLY_LATITUDE')
-AIRLY_LONGITUDE = os.getenv('AIRLY_LONGITUDE')
-AIRLY_MAX_DISTANCE_KM = os.getenv('AIRLY_MAX_DISTANCE_KM', '5')
-DEBUG_ENV = os.getenv('DEBUG', 'false')
+AIRLY_API_KEY = CONFIG.airly_api_key
+AIRLY_LATITUDE = CONFIG.airly_latitude
+AIRLY_LONGITUDE = CONFIG.airly_longitude
+AIRLY_MAX_DISTANCE_KM = CONFIG.airly_max_distance_km
+# Set AIR_QUALITY=false for a weather-only display (no Airly calls, slider or AQI scheduler)
+AIR_QUALITY_ENABLED = CONFIG.air_quality_enabled
+# Air quality providers tried in order until one returns data: airly, open-meteo
+AQI_PROVIDERS = CONFIG.aqi_providers
+AIRLY_INSTALLATION_ID = CONFIG.airly_installation_id  # Pin a sensor, skipping the nearest lookup
+# Or pick a sensor by address ("Marszałkowska", "Kraków, Rynek"): the nearby installation whose
+# address contains every comma-separated part; the nearest one when none matches
+AIRLY_INSTALLATION_NAME = CONFIG.airly_installation_name
+AIRLY_NAME_SEARCH_KM = 25  # Radius searched for a named installation
+AIRLY_ADDRESS_FIELDS = ('displayAddress1', 'displayAddress2', 'street', 'number', 'city', 'country')
 
-def parse_bool(value):
-    return str(value).strip().lower() in ('1', 'true', 'yes', 'on')
+def installation_matches(installation, name):
+    """True when every comma-separated part of name appears in the installation's address (case-insensitive)"""
+    address = (installation.get('address') or {}) if isinstance(installation, dict) else {}
+    text = ' '.join(str(address.get(field) or '') for field in AIRLY_ADDRESS_FIELDS).lower()
+    parts = [part.strip().lower() for part in name.split(',') if part.strip()]
+    return bool(parts) and all(part in text for part in parts)
+# Airly indexes tried in order for the status; PM25 falls back to the raw PM2.5 measurement
+AIRLY_INDEX_PRIORITY = CONFIG.airly_index_priority
+DEBUG = CONFIG.debug
+
+# Hours of the scheduled AQI fetches (Airly's free tier allows few requests a day)
+AQI_SCHEDULED_HOURS = (6, 15, 20)
+
+def should_fetch_aqi(now_hour, last_hour, schedule):
+    """True once per scheduled hour: the first minute-tick in it since the last fetch"""
+    return now_hour in schedule and last_hour != now_hour
+
+# Skip the scheduled AQI fetches that fall before sunrise or after sunset (saves Airly quota)
+AQI_DAYTIME_ONLY = CONFIG.aqi_daytime_only
+
+# Short "Good"/"Moderate"/"Bad" air quality status instead of the full phrases (small screens)
+AQI_SIMPLE = CONFIG.aqi_simple
+
+# Append the index value to the status, e.g. "It's ok... · CAQI 58"
+SHOW_AQI_NUMBER = CONFIG.show_aqi_number
+# Decimals of that number, from the provider's raw value (the bar always uses the rounded index)
+AQI_NUMBER_DECIMALS = CONFIG.aqi_number_decimals
+
+# "PM10 at 142% of norm" under the air quality status: the worst of Airly's standards entries
+SHOW_AQI_NORM = CONFIG.show_aqi_norm
+
+# Small per-pollutant bars under the air quality status (Airly measurements only)
+SHOW_POLLUTANTS = CONFIG.show_pollutants
+# Airly value name -> (label, limit in µg/m³ from the EU air quality standards); a bar is full at its limit
+POLLUTANT_LIMITS = {
+    'PM25': ('PM2.5', 25),
+    'PM10': ('PM10', 50),
+    'NO2': ('NO₂', 200),
+    'O3': ('O₃', 120),
+}
+POLLUTANT_BAR_SIZE = (110, 10)  # Width, height in pixels
+# Pollutant labels: concentration in µg/m³ with POLLUTANT_DECIMALS decimals, or percent of the EU limit
+POLLUTANT_UNIT = CONFIG.pollutant_unit
+POLLUTANT_DECIMALS = CONFIG.pollutant_decimals
+
+def pollutant_color(value, limit):
+    """Green up to half the limit, yellow up to the limit, red above it (white in mono mode)"""
+    if DISPLAY_MODE == 'mono':
+        return '#FFFFFF'
+    if value <= limit / 2:
+        return '#4CAF50'
+    if value <= limit:
+        return '#FFC107'
+    return '#F44336'
+
+def format_concentration(value, limit):
+    """Label value of a pollutant reading in POLLUTANT_UNIT ("12.4" or "50%")"""
+    if POLLUTANT_UNIT == 'percent':
+        return f"{format_number(round(value / limit * 100))}%"
+    return format_number(value, POLLUTANT_DECIMALS)
+
+def in_quiet_hours(now, window):
+    """True when now falls inside the (start, end) window; windows may wrap midnight"""
+    if window is None:
+        return False
+    start, end = window
+    minute = now.hour * 60 + now.minute
+    if start <= end:
+        return start <= minute < end
+    return minute >= start or minute < end
+
+# Quiet hours: no network fetches inside this window, e.g. "23:00-05:00" (empty = off)
+QUIET_HOURS = CONFIG.quiet_hours
+
+# Open-Meteo servers: override for a self-hosted instance or the commercial API. OPEN_METEO_API_KEY,
+# when set, is sent as the "apikey" query parameter on forecast and air-quality requests
+OPEN_METEO_URL = CONFIG.open_meteo_url
+OPEN_METEO_AIR_QUALITY_URL = CONFIG.open_meteo_air_quality_url
+OPEN_METEO_API_KEY = CONFIG.open_meteo_api_key
+# Forecast server tried instead of OPEN_METEO_URL on every other attempt once FETCH_FAILURE_THRESHOLD
+# fetches in a row have failed (empty = none)
+OPEN_METEO_BACKUP_URL = CONFIG.open_meteo_backup_url
+
+def open_meteo_url(base, path, query):
+    """Full Open-Meteo request URL, with the API key appended when one is configured"""
+    url = f"{base}{path}?{query}"
+    return f"{url}&apikey={OPEN_METEO_API_KEY}" if OPEN_METEO_API_KEY else url
+
+# Refetch right away when the network comes back after an outage
+RECONNECT_REFETCH = CONFIG.reconnect_refetch
+RECONNECT_DEBOUNCE = CONFIG.reconnect_debounce  # Min seconds between reconnect refetches
+CONNECTIVITY_CHECK_INTERVAL = 60  # Seconds between connectivity probes
+_forecast_server = urlparse(OPEN_METEO_URL)
+CONNECTIVITY_PROBE = (_forecast_server.hostname or 'api.open-meteo.com',
+                      _forecast_server.port or (80 if _forecast_server.scheme == 'http' else 443))
+
+def is_online(timeout=3):
+    """Cheap connectivity probe: can we open a TCP connection to the weather API?"""
+    try:
+        with socket.create_connection(CONNECTIVITY_PROBE, timeout=timeout):
+            return True
+    except OSError:
+        return False
+
+# systemd integration (Type=notify, WatchdogSec=): READY=1 once the window is up, then WATCHDOG=1
+# pings from the Tk event loop. No-op unless systemd started us (NOTIFY_SOCKET set)
+SD_NOTIFY = CONFIG.sd_notify
+
+def sd_notify(message):
+    """Send a state line such as "READY=1" to systemd; False when not running under systemd"""
+    address = os.getenv('NOTIFY_SOCKET')
+    if not SD_NOTIFY or not address:
+        return False
+    if address.startswith('@'):
+        address = '\0' + address[1:]  # abstract socket namespace
+    try:
+        with socket.socket(socket.AF_UNIX, socket.SOCK_DGRAM) as sock:
+            sock.connect(address)
+            sock.sendall(message.encode())
+        return True
+    except OSError as e:
+        print(f"[systemd] Notify failed: {e}")
+        return False
+
+def sd_watchdog_interval():
+    """Seconds between WATCHDOG=1 pings (half of WatchdogSec=), or None when the watchdog is off"""
+    usec = os.getenv('WATCHDOG_USEC')
+    pid = os.getenv('WATCHDOG_PID')
+    if not SD_NOTIFY or not usec or (pid and int(pid) != os.getpid()):
+        return None
+    return int(usec) / 1_000_000 / 2
 
-DEBUG = parse_bool(DEBUG_ENV)
+# Number formatting: comma-decimal locales (de, pl, fr, ...) render 1.5 as "1,5"
+NUMBER_LOCALE = CONFIG.number_locale
+COMMA_DECIMAL_LOCALES = {
+    'cs', 'da', 'de', 'es', 'fi', 'fr', 'it', 'nb', 'nl', 'no', 'pl', 'pt', 'ru', 'sk', 'sv', 'uk'
+}
+
+def format_number(value, decimals=0):
+    """Format a number with the decimal separator of NUMBER_LOCALE"""
+    text = f"{value:.{decimals}f}"
+    language = NUMBER_LOCALE.replace('-', '_').split('_')[0]
+    if language in COMMA_DECIMAL_LOCALES:
+        text = text.replace('.', ',')
+    return text
+
+# Longest condition / air quality line before it is cut with "…" (keeps small screens tidy)
+TEXT_MAX_CHARS = CONFIG.text_max_chars
+
+def truncate_with_ellipsis(text, max_chars=None):
+    """Shorten text to max_chars (TEXT_MAX_CHARS by default), preferring a word boundary"""
+    max_chars = max_chars or TEXT_MAX_CHARS
+    if len(text) <= max_chars:
+        return text
+    cut = text[:max_chars - 1]
+    space = cut.rfind(' ')
+    if space > max_chars // 2:  # don't throw away most of the line for one long word
+        cut = cut[:space]
+    return cut.rstrip(' ,.;:·-') + '…'
+
+# Details line under the condition: wind "12 km/h (gust 28)" (mph with WEATHER_UNITS=imperial)
+# and relative humidity "45% RH"
+SHOW_WIND = CONFIG.show_wind
+SHOW_HUMIDITY = CONFIG.show_humidity
+# Only show wind above WIND_RELEVANT_KMH and humidity outside the comfortable band
+SHOW_ONLY_RELEVANT = CONFIG.show_only_relevant
+WIND_RELEVANT_KMH = CONFIG.wind_relevant_kmh
+HUMIDITY_COMFORT_MIN = CONFIG.humidity_comfort_min  # Percent
+HUMIDITY_COMFORT_MAX = CONFIG.humidity_comfort_max
+
+def wind_is_relevant(speed, unit):
+    """True when the wind speed (in Open-Meteo's unit) reaches WIND_RELEVANT_KMH"""
+    kmh = speed * 1.609344 if 'mp' in unit else speed
+    return kmh >= WIND_RELEVANT_KMH
+
+def humidity_is_relevant(humidity):
+    """True outside the HUMIDITY_COMFORT_MIN..HUMIDITY_COMFORT_MAX band"""
+    return not HUMIDITY_COMFORT_MIN <= humidity <= HUMIDITY_COMFORT_MAX
+
+# Comfort label from temperature and humidity on the details line ("Comfortable" / "Muggy" / "Dry")
+SHOW_COMFORT = CONFIG.show_comfort
+MUGGY_DEW_POINT = 16.0  # °C; above this most people feel the air as sticky
+
+def dew_point(temp_c, humidity):
+    """Dew point in °C (Magnus formula) from the temperature in °C and relative humidity in percent"""
+    gamma = math.log(max(humidity, 1) / 100) + 17.62 * temp_c / (243.12 + temp_c)
+    return 243.12 * gamma / (17.62 - gamma)
+
+def comfort_level(temp_c, humidity):
+    """'Muggy' from MUGGY_DEW_POINT, 'Dry' below HUMIDITY_COMFORT_MIN, otherwise 'Comfortable'"""
+    if dew_point(temp_c, humidity) >= MUGGY_DEW_POINT:
+        return 'Muggy'
+    if humidity < HUMIDITY_COMFORT_MIN:
+        return 'Dry'
+    return 'Comfortable'
+
+def format_wind(speed, gusts, unit):
+    """Wind speed, with gusts only when they clearly exceed it (1.5x and at least 5 units more)"""
+    text = f"{format_number(round(speed))} {unit}"
+    if gusts is not None and gusts >= speed * 1.5 and gusts - speed >= 5:
+        text += f" (gust {format_number(round(gusts))})"
+    return text
+
+# Append the current hour's precipitation to the condition ("Slight rain · 1.2mm/h")
+CONDITION_SHOW_PRECIP = CONFIG.condition_show_precip
+
+# Sea-level pressure under the wind line, with a trend arrow from the readings of the last few hours
+SHOW_PRESSURE = CONFIG.show_pressure
+PRESSURE_TREND_HOURS = 3  # Window the trend is measured over
+PRESSURE_TREND_MIN_CHANGE = 1.0  # hPa over the window before it counts as rising/falling
+PRESSURE_TREND_ARROWS = {'rising': '↑', 'steady': '→', 'falling': '↓'}
+
+def pressure_trend(readings, window_hours=PRESSURE_TREND_HOURS, min_change=PRESSURE_TREND_MIN_CHANGE):
+    """'rising', 'steady' or 'falling' from (datetime, hPa) readings, oldest first; None with fewer than two
+
+    Compares the latest reading with the oldest one inside the window.
+    """
+    if len(readings) < 2:
+        return None
+    latest_time, latest = readings[-1]
+    window = [value for when, value in readings if (latest_time - when).total_seconds() <= window_hours * 3600]
+    if len(window) < 2:
+        return None
+    change = latest - window[0]
+    if change >= min_change:
+        return 'rising'
+    if change <= -min_change:
+        return 'falling'
+    return 'steady'
+
+# "Rain expected ~16:00" / "No rain next 12h" from the hourly forecast
+SHOW_NEXT_RAIN = CONFIG.show_next_rain
+RAIN_PROBABILITY_THRESHOLD = CONFIG.rain_probability_threshold  # Percent
+NEXT_RAIN_HOURS = 12  # Hours ahead that are scanned
+
+# "08:00: Light drizzle, 9°" under the rain line: the forecast for a fixed time of day (e.g. the commute)
+FORECAST_TIME = CONFIG.forecast_time
+
+# Row of the next HOURLY_HOURS hourly temperatures above the summary line, the current hour boxed as "Now"
+SHOW_HOURLY = CONFIG.show_hourly
+HOURLY_HOURS = CONFIG.hourly_hours
+
+# Data source credit in the bottom-right corner ("Weather: Open-Meteo · Air: Airly"), as the providers ask
+SHOW_ATTRIBUTION = CONFIG.show_attribution
+
+# Small "Updating…" in the bottom-left corner while a weather or air quality fetch is in flight
+SHOW_FETCH_INDICATOR = CONFIG.show_fetch_indicator
+
+# One-sentence summary of the day at the bottom ("Partly cloudy, high 22°, light rain this afternoon, air quality good.")
+SHOW_SUMMARY = CONFIG.show_summary
+
+# Pollen from Open-Meteo's air-quality API (Europe only, no API key needed)
+SHOW_POLLEN = CONFIG.show_pollen
+POLLEN_TYPES = {
+    'alder_pollen': 'Alder',
+    'birch_pollen': 'Birch',
+    'grass_pollen': 'Grass',
+    'mugwort_pollen': 'Mugwort',
+    'olive_pollen': 'Olive',
+    'ragweed_pollen': 'Ragweed',
+}
+
+def pollen_level(grains):
+    """Rough level for a pollen concentration in grains/m³"""
+    if grains < 1:
+        return 'none'
+    if grains < 20:
+        return 'low'
+    if grains < 100:
+        return 'moderate'
+    return 'high'
+
+# Number of forecast days requested from Open-Meteo (clamped to 1-7)
+FORECAST_DAYS = CONFIG.forecast_days
+
+# Heat safety tint: shift the background toward red when the apparent
+# ("feels like") temperature is above HEAT_TINT_THRESHOLD (°C), regardless of weather
+HEAT_TINT = CONFIG.heat_tint
+HEAT_TINT_THRESHOLD = CONFIG.heat_tint_threshold
+
+def to_celsius(value, unit):
+    """Normalize a temperature to °C given Open-Meteo's unit string ("°C" or "°F")"""
+    if unit and 'F' in unit:
+        return (value - 32) * 5 / 9
+    return value
+
+# Temperature units: metric (°C), imperial (°F, requested from Open-Meteo) or kelvin ("291 K")
+WEATHER_UNITS = CONFIG.weather_units
+
+# Temperature suffix: bare "18°" by default, "18°C" / "18°F" with TEMP_SHOW_UNIT,
+# "18 °C" with TEMP_UNIT_SPACE as well (kelvin is always "291 K")
+TEMP_SHOW_UNIT = CONFIG.temp_show_unit
+TEMP_UNIT_SPACE = CONFIG.temp_unit_space
+
+def temperature_suffix():
+    """Degree sign / unit appended to every displayed temperature, placeholders included"""
+    if WEATHER_UNITS == 'kelvin':
+        return ' K'
+    if not TEMP_SHOW_UNIT:
+        return '°'
+    symbol = '°F' if WEATHER_UNITS == 'imperial' else '°C'
+    return f" {symbol}" if TEMP_UNIT_SPACE else symbol
+
+# Big number: the measured temperature ('actual') or the feels-like one ('apparent',
+# with the measured value shown small beside it)
+PRIMARY_TEMP = CONFIG.primary_temp
+
+# Units requested from Open-Meteo explicitly (never its defaults): current field -> (expected
+# current_units string, request parameter value); responses are checked against them
+REQUESTED_UNITS = {
+    'temperature_2m': ('°F', 'fahrenheit') if WEATHER_UNITS == 'imperial' else ('°C', 'celsius'),
+    'wind_speed_10m': ('mp/h', 'mph') if WEATHER_UNITS == 'imperial' else ('km/h', 'kmh'),
+}
+
+def unit_mismatches(data):
+    """(field, expected, got) for each requested unit the response's current_units contradicts"""
+    units = data.get('current_units') or {}
+    return [(field, expected, units[field]) for field, (expected, _) in REQUESTED_UNITS.items()
+            if field in units and units[field] != expected]
+
+def format_temperature(value, unit):
+    """Render a temperature given in Open-Meteo's unit string in WEATHER_UNITS"""
+    celsius = to_celsius(value, unit)
+    if WEATHER_UNITS == 'kelvin':
+        return f"{format_number(round(celsius + 273.15))}{temperature_suffix()}"
+    if WEATHER_UNITS == 'imperial':
+        return f"{format_number(round(celsius * 9 / 5 + 32))}{temperature_suffix()}"
+    return f"{format_number(round(celsius))}{temperature_suffix()}"
+
+def blend_rgb(color, target, amount):
+    """Mix an (r, g, b) color toward target by amount, clamped to 0..1 so channels stay within 0-255"""
+    amount = min(1.0, max(0.0, amount))
+    return tuple(int(c + (t - c) * amount) for c, t in zip(color, target))
+
+# Color the temperature number by value: blue at TEMP_COLOR_MIN °C, red at TEMP_COLOR_MAX °C (off = white)
+TEMP_COLOR_SCALE = CONFIG.temp_color_scale
+TEMP_COLOR_MIN = CONFIG.temp_color_min
+TEMP_COLOR_MAX = CONFIG.temp_color_max
+TEMP_COLOR_COLD = (90, 170, 255)
+TEMP_COLOR_HOT = (255, 90, 70)
+
+def temperature_to_color(temp_c, low=TEMP_COLOR_MIN, high=TEMP_COLOR_MAX):
+    """'#rrggbb' on a blue -> red ramp, clamped to the low..high °C range"""
+    if high <= low:
+        return '#FFFFFF'
+    amount = min(1.0, max(0.0, (temp_c - low) / (high - low)))
+    return '#%02x%02x%02x' % blend_rgb(TEMP_COLOR_COLD, TEMP_COLOR_HOT, amount)
+
+# GPS auto-location via gpsd, only active when GPSD_ADDR is set (e.g. "localhost:2947")
+GPSD_ADDR = CONFIG.gpsd_addr
+GPSD_TIMEOUT = 10  # Seconds to wait for a fix before keeping the configured coordinates
+GPS_REFRESH_INTERVAL = CONFIG.gps_refresh_interval  # Seconds between fixes (at least 60)
+# Refetch everything at once when a fix is this far from the last fetched location (0 = wait for the schedules)
+RELOCATE_DISTANCE_KM = CONFIG.relocate_distance_km
+
+def haversine_km(lat1, lon1, lat2, lon2):
+    """Great-circle distance between two coordinates in kilometres"""
+    lat1, lon1, lat2, lon2 = (math.radians(float(value)) for value in (lat1, lon1, lat2, lon2))
+    a = (math.sin((lat2 - lat1) / 2) ** 2
+         + math.cos(lat1) * math.cos(lat2) * math.sin((lon2 - lon1) / 2) ** 2)
+    return 2 * 6371.0 * math.asin(math.sqrt(a))
+
+def query_gpsd(addr, timeout=GPSD_TIMEOUT):
+    """Return (lat, lon) from the first gpsd TPV report with a 2D/3D fix, or None"""
+    host, _, port = addr.partition(':')
+    deadline = time.monotonic() + timeout
+    try:
+        with socket.create_connection((host or 'localhost', int(port or 2947)), timeout=timeout) as sock:
+            sock.sendall(b'?WATCH={"enable":true,"json":true};\n')
+            buffer = b''
+            while time.monotonic() < deadline:
+                sock.settimeout(max(0.1, deadline - time.monotonic()))
+                chunk = sock.recv(4096)
+                if not chunk:
+                    break
+                buffer += chunk
+                while b'\n' in buffer:
+                    line, buffer = buffer.split(b'\n', 1)
+                    try:
+                        report = json.loads(line)
+                    except ValueError:
+                        continue
+                    if (report.get('class') == 'TPV' and report.get('mode', 0) >= 2
+                            and 'lat' in report and 'lon' in report):
+                        return report['lat'], report['lon']
+    except (OSError, ValueError) as e:
+        print(f"[GPS] gpsd query failed: {e}")
+    return None
+
+# Upper CAQI bound of each status band (AQI_BANDS="33,66,99,150"); the last band is open-ended
+AQI_BANDS = CONFIG.aqi_bands
+
+# Index value at the bad (left) end of the slider; higher values peg there (defaults to the top AQI band)
+AQI_BAR_MAX = CONFIG.aqi_bar_max
+
+# Physical alert: drive a GPIO pin (buzzer/LED) while a freeze or hazardous-air alert is active
+ALERT_GPIO_PIN = CONFIG.alert_gpio_pin
+HAZARDOUS_CAQI = AQI_BANDS.high  # Above this caqi_to_status reports "Hazardous"
+
+# "Deteriorating" after the status when the index rose by AQI_RISE_DELTA or more between two
+# consecutive readings at most AQI_RISE_MINUTES apart (0 = off). Scheduled fetches are hours
+# apart, so the default window only catches refetches; raise it to compare those too
+AQI_RISE_DELTA = CONFIG.aqi_rise_delta
+AQI_RISE_MINUTES = CONFIG.aqi_rise_minutes
+
+def aqi_rising(readings, delta=AQI_RISE_DELTA, window_minutes=AQI_RISE_MINUTES):
+    """True when the last two (datetime, provider, score) readings show a rapid rise
+
+    Readings from different providers (CAQI vs European AQI) are never compared;
+    fewer than two readings is never a rise.
+    """
+    if not delta or len(readings) < 2:
+        return False
+    (before, provider_before, score_before), (after, provider_after, score_after) = readings[-2:]
+    if provider_before != provider_after:
+        return False
+    return (after - before).total_seconds() <= window_minutes * 60 and score_after - score_before >= delta
+
+def open_alert_output(pin):
+    """gpiozero output for the alert pin, or None when unset or unavailable (non-Pi builds)"""
+    if not pin:
+        return None
+    try:
+        from gpiozero import OutputDevice
+        device = OutputDevice(int(pin), initial_value=False)
+        print(f"[Alert] Driving GPIO {pin} for alerts")
+        return device
+    except Exception as e:
+        print(f"[Alert] GPIO {pin} unavailable, alerts stay on-screen only: {e}")
+        return None
+
+# Screen brightness per time phase in percent, through the first /sys/class/backlight device
+# (e.g. the official Pi touchscreen); all 100 (default) leaves the backlight untouched
+PHASE_BRIGHTNESS = CONFIG.phase_brightness
+BRIGHTNESS_RAMP_SECONDS = 3  # A phase change fades to the new level over this long
+BRIGHTNESS_RAMP_STEPS = 15
+BACKLIGHT_DIR = '/sys/class/backlight'
+
+def open_backlight():
+    """(current percent, function setting the brightness in percent), or None without a writable backlight"""
+    if all(level == 100 for level in PHASE_BRIGHTNESS.values()):
+        return None
+    try:
+        name = sorted(os.listdir(BACKLIGHT_DIR))[0]
+        device = os.path.join(BACKLIGHT_DIR, name)
+        with open(os.path.join(device, 'max_brightness')) as f:
+            maximum = int(f.read())
+        with open(os.path.join(device, 'brightness')) as f:
+            current = int(f.read()) * 100 / maximum
+    except (OSError, ValueError, IndexError, ZeroDivisionError) as e:
+        print(f"[Brightness] No usable backlight under {BACKLIGHT_DIR}, ignoring BRIGHTNESS_* ({e})")
+        return None
+
+    def set_percent(percent):
+        with open(os.path.join(device, 'brightness'), 'w') as f:
+            f.write(str(round(maximum * percent / 100)))
+
+    print(f"[Brightness] Controlling backlight {name} (max {maximum})")
+    return current, set_percent
+
+# Optional indoor sensor shown under the outdoor temperature: "dht22:<BCM pin>" or
+# "bme280[:<I2C address>]" (empty = off; the driver libraries are only imported when configured)
+INDOOR_SENSOR = CONFIG.indoor_sensor
+INDOOR_SENSOR_INTERVAL = CONFIG.indoor_sensor_interval  # Seconds between readings (at least 10)
+
+def open_indoor_sensor(spec):
+    """Function returning the indoor temperature in °C, or None when unset or unavailable"""
+    if not spec:
+        return None
+    kind, _, arg = spec.partition(':')
+    try:
+        if kind == 'dht22':
+            import adafruit_dht
+            import board
+            device = adafruit_dht.DHT22(getattr(board, f"D{int(arg)}"))
+            read = lambda: device.temperature
+        elif kind == 'bme280':
+            import bme280
+            import smbus2
+            bus = smbus2.SMBus(1)
+            address = int(arg or '0x76', 16)
+            calibration = bme280.load_calibration_params(bus, address)
+            read = lambda: bme280.sample(bus, address, calibration).temperature
+        else:
+            print(f"[Indoor] Unknown INDOOR_SENSOR {spec!r}, expected dht22:<pin> or bme280[:<address>]")
+            return None
+    except Exception as e:
+        print(f"[Indoor] Sensor {spec} unavailable, indoor temperature hidden: {e}")
+        return None
+    print(f"[Indoor] Reading {kind} every {INDOOR_SENSOR_INTERVAL}s")
+    return read
+
+# Clock visibility and placement (top-left, top-right, bottom-left, bottom-right)
+SHOW_CLOCK = CONFIG.show_clock
+CLOCK_POSITION = CONFIG.clock_position
+
+# Diagnostics line at the bottom: last fetch outcome per source, e.g. "WX:ok 14:03 · AQI:stale 06:00 (Airly)"
+SHOW_HEALTH = CONFIG.show_health
+
+# Bedside mode: during the night phase show only a large, dim clock on near-black
+NIGHT_FACE = CONFIG.night_face
+NIGHT_FACE_BACKGROUND = '#050505'
+NIGHT_FACE_CLOCK_COLOR = '#7a7a7a'
+# A tap (or a new hazardous-air alert) shows the full layout for at least this long before the night face returns
+NIGHT_FACE_WAKE_SECONDS = CONFIG.night_face_wake_seconds
 
 # Location configuration (from .env)
 LOCATION = {
-    'city': os.getenv('LOCATION_CITY', 'Berlin'),
-    'country': os.getenv('LOCATION_COUNTRY', 'Germany')
+    'city': CONFIG.location_city,
+    'country': CONFIG.location_country
 }
 
+# Fallback coordinates when geocoding fails (Berlin); DEFAULT_LOCATION="lat,lon" overrides them
+DEFAULT_LAT = 52.52
+DEFAULT_LON = 13.405
+
+DEFAULT_LOCATION = CONFIG.default_location
+
+# Decimals kept in the coordinates sent to the APIs (4 = ~11 m); stable URLs and cache keys
+COORD_PRECISION = CONFIG.coord_precision
+
+def round_coordinate(value):
+    """Round a latitude/longitude (number or string) to COORD_PRECISION; unparseable values pass through"""
+    try:
+        return round(float(value), COORD_PRECISION)
+    except (TypeError, ValueError):
+        return value
+
+# Place label ("Warsaw, PL") at the top; names for bare coordinates come from
+# reverse geocoding and are cached on disk per ~1 km cell
+SHOW_LOCATION = CONFIG.show_location
+PLACE_NAMES_CACHE_FILE = 'place_names.json'
+
+def reverse_geocode(lat, lon):
+    """'City, CC' for the coordinates via OpenStreetMap Nominatim; raises on failure"""
+    # The Nominatim usage policy requires an identifying User-Agent (USER_AGENT)
+    response = http_get(
+        'https://nominatim.openstreetmap.org/reverse',
+        params={'lat': lat, 'lon': lon, 'format': 'jsonv2', 'zoom': 10, 'accept-language': 'en'},
+        timeout=10,
+    )
+    response.raise_for_status()
+    address = response.json().get('address') or {}
+    place = next((address[key] for key in ('city', 'town', 'village', 'municipality', 'county', 'state')
+                  if address.get(key)), None)
+    if not place:
+        raise ValueError('no place name in response')
+    country = (address.get('country_code') or '').upper()
+    return f"{place}, {country}" if country else place
+
 # Weather code mapping (Open-Meteo WMO codes)
 WEATHER_CODES = {
     0: 'Clear sky',
@@ -72,9 +1165,13 @@ WEATHER_CODES = {
     51: 'Light drizzle',
     53: 'Moderate drizzle',
     55: 'Dense drizzle',
+    56: 'Light freezing drizzle',
+    57: 'Dense freezing drizzle',
     61: 'Slight rain',
     63: 'Moderate rain',
     65: 'Heavy rain',
+    66: 'Light freezing rain',
+    67: 'Heavy freezing rain',
     71: 'Slight snow',
     73: 'Moderate snow',
     75: 'Heavy snow',
@@ -89,15 +1186,360 @@ WEATHER_CODES = {
     99: 'Thunderstorm with heavy hail'
 }
 
+# Condition text for codes missing from WEATHER_CODES (e.g. ones Open-Meteo adds later)
+UNKNOWN_CONDITION_TEXT = CONFIG.unknown_condition_text
+_reported_unknown_codes = set()
+
+def weather_description(code):
+    """Condition text for a WMO code; unmapped codes are logged once so they can be added"""
+    if code in WEATHER_CODES:
+        return WEATHER_CODES[code]
+    if code not in _reported_unknown_codes:
+        _reported_unknown_codes.add(code)
+        print(f"[Weather] Unmapped weather code {code!r}, showing {UNKNOWN_CONDITION_TEXT!r}")
+    return UNKNOWN_CONDITION_TEXT
+
+# Weather code categories shared by the condition text and the gradient.
+# 0 (clear) and 1 (mainly clear) count as clear; 2 (partly cloudy) onwards as cloudy.
+# Freezing drizzle/rain (56, 57, 66, 67) falls as liquid, so it is rain.
+CLEAR_CODES = {0, 1}
+RAIN_CODES = {51, 53, 55, 56, 57, 61, 63, 65, 66, 67, 80, 81, 82, 95, 96, 99}
+SNOW_CODES = {71, 73, 75, 77, 85, 86}
+CLOUDY_CODES = {2, 3, 45, 48}
+
+def parse_local_time(value):
+    """Parse Open-Meteo's local ISO time ("2024-06-01T04:43"), or None"""
+    try:
+        return datetime.fromisoformat(value) if value else None
+    except (TypeError, ValueError):
+        return None
+
+def next_sun_event(now, sun_days):
+    """('Sunrise' | 'Sunset', time) of the next sun event after now
+
+    sun_days is a list of (sunrise, sunset) per day starting today. Returns
+    None when the data runs out or a day has no sunrise/sunset (polar day or
+    night), so the countdown is hidden rather than wrong.
+    """
+    for sunrise, sunset in sun_days:
+        if sunrise is None or sunset is None:
+            return None
+        if now < sunrise:
+            return 'Sunrise', sunrise
+        if now < sunset:
+            return 'Sunset', sunset
+    return None
+
+# Derive the gradient phases from sunrise/sunset instead of fixed clock hours: the fetched sun
+# times when available, otherwise an estimate from the latitude and date (high latitudes)
+PHASE_FROM_SUN = CONFIG.phase_from_sun
+
+def fixed_time_phase(hour):
+    """Phase for an hour (0-23) on the fixed schedule
+
+    night 21:00-04:59 (wraps midnight), sunrise 05:00-07:59, day 08:00-16:59,
+    sunset 17:00-20:59. So hour 4 is night, 5 sunrise, 8 day, 17 sunset, 21 night.
+    """
+    if hour >= 21 or hour < 5:
+        return 'night'
+    if hour < 8:
+        return 'sunrise'
+    if hour >= 17:
+        return 'sunset'
+    return 'day'
+
+def estimate_sun_hours(day, latitude, solar_noon=12.0):
+    """(sunrise, sunset) as fractional local hours for a date and latitude
+
+    Uses the solar declination for the day of the year; solar_noon shifts the
+    result from solar to clock time. Returns (0, 24) during polar day and None
+    during polar night.
+    """
+    declination = math.radians(23.44) * math.sin(2 * math.pi * (284 + day.timetuple().tm_yday) / 365)
+    cos_hour_angle = -math.tan(math.radians(latitude)) * math.tan(declination)
+    if cos_hour_angle <= -1:
+        return 0.0, 24.0
+    if cos_hour_angle >= 1:
+        return None
+    half_day = math.degrees(math.acos(cos_hour_angle)) / 15
+    return solar_noon - half_day, solar_noon + half_day
+
+def phase_from_sun_hours(hour, sun_hours):
+    """Time phase for a fractional hour given (sunrise, sunset) hours, or None for polar night
+
+    Mirrors the fixed schedule around a 06:00/18:00 day: 'sunrise' from an hour
+    before sunrise to two hours after, 'sunset' from an hour before sunset to
+    three hours after.
+    """
+    if sun_hours is None:
+        return 'night'
+    sunrise, sunset = sun_hours
+    if sunrise <= 0 and sunset >= 24:
+        return 'day'
+    if hour < sunrise - 1 or hour >= sunset + 3:
+        return 'night'
+    if hour >= sunset - 1:
+        return 'sunset'
+    if hour < sunrise + 2:
+        return 'sunrise'
+    return 'day'
+
+def current_hour_index(data, now):
+    """Index of the hourly row for the current hour, or None
+
+    The hourly arrays start at local midnight, not at the current hour, so
+    index 0 is usually in the past. The hour is taken in the location's
+    timezone: now shifted by the response's utc_offset_seconds, else the
+    response's current.time (the fetch time), else now as it is.
+    """
+    offset = data.get('utc_offset_seconds')
+    if isinstance(offset, (int, float)):
+        current_time = (now.astimezone(timezone.utc) + timedelta(seconds=offset)).strftime('%Y-%m-%dT%H')
+    else:
+        current_time = (data.get('current') or {}).get('time') or now.strftime('%Y-%m-%dT%H')
+    times = (data.get('hourly') or {}).get('time') or []
+    # Hourly rows are "YYYY-MM-DDTHH:00"; match on the hour prefix
+    hour_prefix = current_time[:13]
+    for i, row_time in enumerate(times):
+        if row_time.startswith(hour_prefix):
+            return i
+    return None
+
+def next_rain(data, now, threshold=None, hours=NEXT_RAIN_HOURS):
+    """Start time of the first of the next `hours` hourly rows that looks rainy, or None when all dry
+
+    A row is rainy when its precipitation_probability reaches threshold (percent,
+    RAIN_PROBABILITY_THRESHOLD by default); rows without a probability count as
+    rainy from 0.1 mm of precipitation.
+    """
+    threshold = RAIN_PROBABILITY_THRESHOLD if threshold is None else threshold
+    hourly = data.get('hourly') or {}
+    times = hourly.get('time') or []
+    probabilities = hourly.get('precipitation_probability') or []
+    amounts = hourly.get('precipitation') or []
+    start = current_hour_index(data, now)
+    if start is None:
+        return None
+    for i in range(start + 1, min(start + 1 + hours, len(times))):
+        probability = probabilities[i] if i < len(probabilities) else None
+        amount = amounts[i] if i < len(amounts) else None
+        if probability is not None:
+            rainy = probability >= threshold
+        else:
+            rainy = amount is not None and amount >= 0.1
+        if rainy:
+            return parse_local_time(times[i])
+    return None
+
+def forecast_at(data, now, at):
+    """(time, weather code, temperature) of the hourly row for the next `at` (hour, minute), or None
+
+    Today's row while that time is still ahead, otherwise tomorrow's; minutes
+    fall into their hour's row. "Now" is the response's current.time (the
+    location's timezone) when present.
+    """
+    now = parse_local_time((data.get('current') or {}).get('time')) or now
+    target = now.replace(hour=at[0], minute=at[1], second=0, microsecond=0)
+    if target <= now:
+        target += timedelta(days=1)
+    hourly = data.get('hourly') or {}
+    codes = hourly.get('weather_code') or []
+    temperatures = hourly.get('temperature_2m') or []
+    hour_prefix = target.strftime('%Y-%m-%dT%H')
+    for i, row_time in enumerate(hourly.get('time') or []):
+        if row_time.startswith(hour_prefix):
+            code = codes[i] if i < len(codes) else None
+            temperature = temperatures[i] if i < len(temperatures) else None
+            if code is None or temperature is None:
+                return None
+            return target, code, temperature
+    return None
+
+def hourly_strip(data, now, hours):
+    """[(time, temperature, is current hour)] for up to `hours` hourly rows from the current hour
+
+    Starts at current_hour_index rather than row 0 (which is usually local
+    midnight); rows without a temperature are left out. [] without hourly data.
+    """
+    start = current_hour_index(data, now)
+    if start is None:
+        return []
+    hourly = data.get('hourly') or {}
+    times = hourly.get('time') or []
+    temperatures = hourly.get('temperature_2m') or []
+    return [
+        (parse_local_time(times[i]), temperatures[i], i == start)
+        for i in range(start, min(start + hours, len(times), len(temperatures)))
+        if temperatures[i] is not None
+    ]
+
+def rain_later_today(data, now):
+    """'light rain this afternoon'-style phrase for the rest of today's hourly rows, or None when dry"""
+    hourly = data.get('hourly') or {}
+    times = hourly.get('time') or []
+    amounts = hourly.get('precipitation') or []
+    start = current_hour_index(data, now)
+    if start is None:
+        return None
+    today = times[start][:10]
+    rainy = [(times[i], amounts[i]) for i in range(start, min(len(times), len(amounts)))
+             if times[i].startswith(today) and amounts[i] is not None and amounts[i] >= 0.1]
+    if not rainy:
+        return None
+    heaviest = max(amount for _, amount in rainy)
+    intensity = 'light' if heaviest < 2.5 else 'moderate' if heaviest < 7.6 else 'heavy'
+    hour = int(rainy[0][0][11:13])
+    when = 'this morning' if hour < 12 else 'this afternoon' if hour < 18 else 'tonight'
+    return f"{intensity} rain {when}"
+
+def summarize(weather, forecast, air_quality):
+    """Today's summary sentence from the weather response, the parsed daily forecast and the
+    simple air quality status ("Good"/"Moderate"/"Bad"); parts without data are left out, None when all are
+    """
+    parts = []
+    code = (weather.get('current') or {}).get('weather_code')
+    if code is not None:
+        parts.append(weather_description(code))
+    high = forecast[0].get('temp_max') if forecast else None
+    if high is not None:
+        unit = (weather.get('daily_units') or {}).get('temperature_2m_max', '°C')
+        parts.append(f"high {format_temperature(high, unit)}")
+    rain = rain_later_today(weather, local_now())
+    if rain:
+        parts.append(rain)
+    if air_quality:
+        parts.append(f"air quality {air_quality.lower()}")
+    if not parts:
+        return None
+    sentence = ", ".join(parts)
+    return f"{sentence[0].upper()}{sentence[1:]}."
+
+def format_countdown(seconds):
+    """Format a duration as "2h 14m" (or "14m" under an hour)"""
+    minutes = max(0, int(seconds // 60))
+    hours, minutes = divmod(minutes, 60)
+    return f"{hours}h {minutes}m" if hours else f"{minutes}m"
+
+def check_asset_coverage():
+    """Debug self-check: every categorised weather code has a label and every core image is available"""
+    problems = []
+    for code in sorted(RAIN_CODES | SNOW_CODES | CLOUDY_CODES):
+        if code not in WEATHER_CODES:
+            problems.append(f"weather code {code} has a category but no label")
+    for code in sorted(set(WEATHER_CODES) - CLEAR_CODES - RAIN_CODES - SNOW_CODES - CLOUDY_CODES):
+        problems.append(f"weather code {code} has a label but no category")
+    for name in CORE_ASSETS:
+        if not os.path.exists(asset_path(name)) and embedded_asset(name) is None:
+            problems.append(f"missing image {asset_path(name)} (and no embedded copy)")
+    for problem in problems:
+        print(f"[Assets] Coverage: {problem}")
+    if not problems:
+        print(f"[Assets] Coverage OK: {len(WEATHER_CODES)} weather codes, {len(CORE_ASSETS)} images")
+    return problems
+
+def parse_airly_standards(data):
+    """[(pollutant, percent of its norm)] from current.standards; [] when absent or malformed"""
+    current = data.get('current') if isinstance(data, dict) else None
+    standards = current.get('standards') if isinstance(current, dict) else None
+    if not isinstance(standards, list):
+        return []
+    return [
+        (entry['pollutant'], entry['percent'])
+        for entry in standards
+        if isinstance(entry, dict) and entry.get('pollutant') and isinstance(entry.get('percent'), (int, float))
+    ]
+
+def parse_airly_current(data):
+    """Extract ({index name: value}, {pollutant name: value}) from an Airly measurement response
+
+    Tolerant of upstream shape changes: missing or malformed indexes/values
+    yield empty dicts, and None is returned only when there is no usable
+    'current' block at all. Non-numeric, NaN and negative readings (Airly's
+    "not computable" sentinels such as -1) are dropped and logged, so they
+    count as unavailable instead of landing in a status band.
+    """
+    current = data.get('current') if isinstance(data, dict) else None
+    if not isinstance(current, dict):
+        return None
+
+    def by_name(entries, field):
+        if entries is None:
+            return {}
+        if not isinstance(entries, list):
+            print(f"[AQI] Unexpected Airly '{field}' shape: {type(entries).__name__}")
+            return {}
+        readings = {}
+        for entry in entries:
+            if not isinstance(entry, dict):
+                continue
+            name, value = entry.get('name'), entry.get('value')
+            if not name:
+                continue
+            if isinstance(value, bool) or not isinstance(value, (int, float)) or math.isnan(value) or value < 0:
+                if value is not None:
+                    print(f"[AQI] Ignoring Airly {field} {name}={value!r} (not a usable reading)")
+                continue
+            readings[name] = value
+        return readings
+
+    return by_name(current.get('indexes'), 'indexes'), by_name(current.get('values'), 'values')
+
+@dataclass(frozen=True)
+class Snapshot:
+    """What the display currently shows, as one serializable value
+
+    The shared source for anything that exports the current state (cache,
+    endpoints, logging). Temperatures are °C regardless of WEATHER_UNITS;
+    times are local ISO strings.
+    """
+    time: str = None  # When this snapshot was last changed
+    temperature: float = None
+    weather_code: int = None
+    condition: str = None
+    caqi: float = None
+    aqi_status: str = None
+    updated_at: str = None  # Time of the last successful weather update
+
+    def to_json(self):
+        return json.dumps(asdict(self))
+
+    @classmethod
+    def from_json(cls, text):
+        return cls(**json.loads(text))
+
+# "full" shows every section; "compact" (small screens) keeps the clock, temperature and
+# condition only, and skips the air quality, pollen and transport fetches entirely
+LAYOUT_MODE = CONFIG.layout_mode
+SHOW_TRANSPORT = CONFIG.show_transport
+
+# "color" (default) or "mono" for e-paper / monochrome panels: a solid black background,
+# white text, grayscale slider images and no color cues (temperature scale, heat tint)
+DISPLAY_MODE = CONFIG.display_mode
+
+def monochrome(image):
+    """Grayscale copy of a PIL image that keeps its transparency (DISPLAY_MODE=mono)"""
+    return image.convert('LA').convert('RGBA')
+
+def weather_code_category(code):
+    """Map a WMO weather code to 'rain', 'snow', 'cloudy' or 'clear'"""
+    if code in RAIN_CODES:
+        return 'rain'
+    if code in SNOW_CODES:
+        return 'snow'
+    if code in CLOUDY_CODES:
+        return 'cloudy'
+    return 'clear'
+
 
 class WeatherDisplay:
-    def __init__(self, root):
+    def __init__(self, root, demo=False):
         self.root = root
+        self.demo = demo
         self.root.title("Weather Display")
         
         # Make fullscreen
         self.root.attributes('-fullscreen', True)
-        self.root.configure(bg='#667eea')  # Base gradient color
+        self.root.configure(bg='#000000' if DISPLAY_MODE == 'mono' else '#667eea')  # Base gradient color
         
         # Allow escape key to exit fullscreen (for testing)
         self.root.bind('<Escape>', lambda e: self.root.attributes('-fullscreen', False))
@@ -111,37 +1553,119 @@ class WeatherDisplay:
         self.latitude = None
         self.longitude = None
         self.location_name = ''
+        self.aqi_latitude = round_coordinate(AIRLY_LATITUDE)  # Airly coordinates (replaced by a GPS fix when available)
+        self.aqi_longitude = round_coordinate(AIRLY_LONGITUDE)
         self.last_weather_code = 0
+        self.last_apparent_temp = None
+        self.temperature_history = deque(maxlen=max(SPARKLINE_POINTS, 1))  # Recent temperatures for the sparkline
+        self.pressure_history = deque(maxlen=24)  # (local time, hPa) per weather update, for the trend arrow
+        self._sparkline_box = None  # (left, top) of the sparkline, set on resize
+        self.sun_days = []  # (sunrise, sunset) local datetimes per day, starting today
+        self.daily_forecast = []  # One dict per day: date, weather_code, temp_max, temp_min
+        self.last_weather_data = None  # Last applied forecast response, for the summary line
         self.gradient_start = (102, 126, 234)
         self.gradient_end = (118, 75, 162)
         self.phase_override = None
+        self.night_face = False  # NIGHT_FACE clock-only face currently shown
+        self._wake_until = 0.0  # monotonic time until which a tap/alert keeps the full layout on
+        self._wake_after_id = None
         self.animating = False
         self.last_aqi_fetch_hour = None
         self.debug_enabled = DEBUG
         self.aqi_canvas = None
         self.current_caqi_value = 50  # Store current CAQI for re-applying after resize
-        
+        self.aqi_bar_value = 50  # current_caqi_value clamped to 0..AQI_BAR_MAX, as drawn on the bar
+        self.aqi_indexes = {}  # All Airly indexes from the last measurement (name -> value)
+        self.aqi_index_used = None  # Index name that drove the last status
+        self.pollutants = {}  # Airly pollutant values from the last measurement (name -> µg/m³)
+        self.aqi_standards = []  # Airly (pollutant, percent of norm) from the last measurement
+        self._pollutants_box = None  # (center x, top, width) of the pollutant bars, set on resize
+        self._hourly_box = None  # (center x, top, width) of the SHOW_HOURLY strip, set on resize
+        self.aqi_provider = None  # Provider that served the last AQI value
+        self._named_installation_id = None  # AIRLY_INSTALLATION_NAME resolved to an ID
+        self.aqi_history = deque(maxlen=2)  # (local time, provider, score) of the last readings
+        self.aqi_deteriorating = False
+        self._fetching = set()  # Sources ('weather', 'aqi') with a request in flight
+        self.aqi_available = False  # False until a provider returns data (bar greyed out, no indicator)
+        self.aqi_failed = False  # Last AQI fetch found no provider with data
+        self._aqi_error_text = None  # Specific reason shown instead of "AQI unavailable"
+        self.airly_key_invalid = False  # Last AQI fetch got a 401/403 from Airly (kept while a fallback serves)
+        self.weather_updated_at = None  # Local time of the last successful weather update
+        self.weather_live = False  # A fetch has succeeded this run (weather_updated_at may come from the cache)
+        self.aqi_updated_at = None  # Local time of the last successful AQI update
+        self.weather_stale = False
+        self.weather_failures = 0  # Consecutive failed weather fetches
+        self.weather_degraded = False  # FETCH_FAILURE_THRESHOLD reached, "Weather service unreachable" shown
+        self.temperature_color = '#FFFFFF'  # TEMP_COLOR_SCALE color of the last temperature
+        self.aqi_stale = False
+        self.alert_active = False  # Freeze warning (temperature at or below 0°C)
+        self.aqi_alert = False  # Hazardous air quality
+        self.alert_output = open_alert_output(ALERT_GPIO_PIN)
+        backlight = open_backlight()
+        self.brightness, self.set_backlight = backlight if backlight else (100, None)
+        self._startup_brightness = self.brightness  # Restored on exit
+        self._brightness_phase = None  # Phase whose level was last applied
+        self._brightness_after_id = None
+        self._snapshot = Snapshot()
+        self._snapshot_lock = threading.Lock()  # readers may live on other threads
+        self.ui_errors = deque()  # monotonic times of recent UI callback errors
+        self.ui_failed = False  # Set when the error burst asks main() to rebuild the window
+        self._stopped_timers = set()  # Timers that found the canvas gone and stopped rescheduling
+        self.root.report_callback_exception = self.report_ui_error
+        self.indoor_sensor = open_indoor_sensor(INDOOR_SENSOR)
+
         # Performance optimizations for Pi Zero
         self._font_cache = {}  # Cache Font objects to avoid repeated creation
         self._resize_after_id = None  # Debounce resize events
         self._weather_after_id = None  # Scheduled weather update
         self._time_after_id = None  # Scheduled time update
         self._aqi_after_id = None  # Scheduled AQI update
+        # FETCH_SPREAD phase of this display: extra delay before the first periodic weather fetch (s)
+        # and minutes into each AQI_SCHEDULED_HOURS hour
+        self.weather_fetch_offset = random.uniform(0, REFRESH_INTERVAL) if FETCH_SPREAD else 0
+        self.aqi_fetch_offset = random.randrange(60) if FETCH_SPREAD else 0
         self._transport_after_id = None  # Scheduled transport API update
         self._transport_display_after_id = None  # Scheduled transport display update
+        self._gps_after_id = None  # Scheduled gpsd location refresh
+        self._connectivity_after_id = None  # Scheduled connectivity probe
+        self._indoor_after_id = None  # Scheduled indoor sensor reading
+        self._sd_watchdog_after_id = None  # Scheduled systemd watchdog ping
+        self._was_online = True
+        self._in_quiet_hours = False
+        self._start_deferred = False  # start_fetching found quiet hours; it runs again when they end
+        self._last_clock_tick = None  # Wall time of the last minute tick (monotonic pauses during suspend)
+        self._last_reconnect_refetch = None  # monotonic time of the last reconnect-triggered refetch
+        self._fetch_cache = {}  # (endpoint, lat, lon) -> (monotonic time, response)
+        self._fetch_cache_lock = threading.Lock()  # The fetch worker uses the cache too
+        self._fetched_location = None  # (lat, lon) the data on screen was fetched for (GPS relocation check)
+        self._last_weather_attempt = None  # monotonic time of the last weather fetch (ok or failed)
+        # BACKGROUND_FETCH: jobs go to the worker thread, results come back for the Tk thread
+        self._fetch_jobs = queue.Queue()
+        self._fetch_results = queue.Queue()
+        self._fetch_worker = None
+        self._fetch_poll_after_id = None
+        self._weather_in_flight = False
+        self._last_aqi_tick = None  # monotonic time of the last AQI scheduler tick
         
         # Cached transport departures (raw data with timestamps for live countdown)
         self._cached_departures_row1 = []
         self._cached_departures_row2 = []
         
-        # Create UI elements
-        self.create_widgets()
-        
-        # Create gradient
-        self.root.after(100, self.draw_gradient)
+        if self.debug_enabled:
+            check_asset_coverage()
         
-        # Start fetching data
-        self.start_updates()
+        try:
+            # Create UI elements
+            self.create_widgets()
+            
+            # Create gradient
+            self.root.after(100, self.draw_gradient)
+            
+            # Start fetching data
+            self.start_updates()
+        except Exception:
+            self.shutdown()  # run_display never gets the object: release the pin and backlight here
+            raise
     
     def create_widgets(self):
         """Create all UI widgets for new 3-section layout"""
@@ -154,32 +1678,176 @@ class WeatherDisplay:
             font=('IBM Plex Mono', 90, 'bold italic'),
             fill='#FFFFFF',
             anchor='w',
+            state='normal' if SHOW_CLOCK else 'hidden',
             tags=('datetime',)
         )
 
+        # Next sunrise/sunset countdown (hidden until sun times are known)
+        self.canvas.create_text(
+            0, 0,
+            text="",
+            font=('IBM Plex Mono', 20, 'bold italic'),
+            fill='#FFFFFF',
+            anchor='w',
+            state='hidden',
+            tags=('sun_countdown',)
+        )
+
+        # Next rain estimate (under the sun countdown, SHOW_NEXT_RAIN only)
+        self.canvas.create_text(
+            0, 0,
+            text="",
+            font=('IBM Plex Mono', 20, 'bold italic'),
+            fill='#FFFFFF',
+            anchor='w',
+            state='hidden',
+            tags=('next_rain',)
+        )
+
+        # Forecast for FORECAST_TIME (under the next rain line)
+        self.canvas.create_text(
+            0, 0,
+            text="",
+            font=('IBM Plex Mono', 20, 'bold italic'),
+            fill='#FFFFFF',
+            anchor='w',
+            state='hidden',
+            tags=('forecast_time',)
+        )
+
+        # Place name (top center, small)
+        self.canvas.create_text(
+            0, 0,
+            text="",
+            font=('IBM Plex Mono', 16, 'italic'),
+            fill='#FFFFFF',
+            anchor='n',
+            state='normal' if SHOW_LOCATION else 'hidden',
+            tags=('location',)
+        )
+
         # Temperature (right-aligned, large)
         self.canvas.create_text(
             0, 0,
-            text="--°",
+            text=f"--{temperature_suffix()}",
             font=('IBM Plex Mono', 90, 'bold italic'),
             fill='#FFFFFF',
             anchor='e',
             tags=('temperature',)
         )
 
+        # Frost badge (shown beside the temperature at or below freezing)
+        self.canvas.create_text(
+            0, 0,
+            text="❄",
+            font=('IBM Plex Mono', 28, 'bold'),
+            fill='#d6f0ff',
+            anchor='ne',
+            state='hidden',
+            tags=('frost_badge',)
+        )
+
+        # Measured temperature beside the big feels-like number (PRIMARY_TEMP=apparent only)
+        self.canvas.create_text(
+            0, 0,
+            text="",
+            font=('IBM Plex Mono', 16, 'bold italic'),
+            fill='#FFFFFF',
+            anchor='se',
+            state='hidden',
+            tags=('secondary_temperature',)
+        )
+
+        # Weather condition (under the temperature)
+        self.canvas.create_text(
+            0, 0,
+            text="",
+            font=('IBM Plex Mono', 20, 'bold italic'),
+            fill='#FFFFFF',
+            anchor='e',
+            tags=('description',)
+        )
+
+        # Indoor temperature (under the sparkline, INDOOR_SENSOR only)
+        self.canvas.create_text(
+            0, 0,
+            text="",
+            font=('IBM Plex Mono', 20, 'bold italic'),
+            fill='#FFFFFF',
+            anchor='e',
+            state='normal' if self.indoor_sensor else 'hidden',
+            tags=('indoor_temperature',)
+        )
+
+        # Wind and humidity details (under the indoor temperature, SHOW_WIND / SHOW_HUMIDITY only)
+        self.canvas.create_text(
+            0, 0,
+            text="",
+            font=('IBM Plex Mono', 20, 'bold italic'),
+            fill='#FFFFFF',
+            anchor='e',
+            state='hidden',
+            tags=('details',)
+        )
+
+        # Pressure and trend arrow (under the wind, SHOW_PRESSURE only)
+        self.canvas.create_text(
+            0, 0,
+            text="",
+            font=('IBM Plex Mono', 20, 'bold italic'),
+            fill='#FFFFFF',
+            anchor='e',
+            state='hidden',
+            tags=('pressure',)
+        )
+
         # === SECTION 2: AIR QUALITY SLIDER ===
-        # Canvas for AQI slider (will be positioned in resize)
-        self.aqi_canvas = tk.Canvas(self.root, highlightthickness=0, bg='#667eea')
-        self.canvas.create_window(
+        # Canvas for AQI slider (will be positioned in resize); skipped without air quality
+        if AIR_QUALITY_ENABLED:
+            self.aqi_canvas = tk.Canvas(self.root, highlightthickness=0, bg='#667eea')
+            self.canvas.create_window(
+                0, 0,
+                window=self.aqi_canvas,
+                anchor='center',
+                tags=('aqi_slider',)
+            )
+            
+            # Verbal air quality status (below the slider)
+            self.canvas.create_text(
+                0, 0,
+                text="",
+                font=('IBM Plex Mono', 20, 'bold italic'),
+                fill='#FFFFFF',
+                anchor='center',
+                tags=('aqi_status',)
+            )
+            
+            # Worst pollutant against its norm (below the status, SHOW_AQI_NORM only)
+            self.canvas.create_text(
+                0, 0,
+                text="",
+                font=('IBM Plex Mono', 16, 'italic'),
+                fill='#FFFFFF',
+                anchor='center',
+                state='hidden',
+                tags=('aqi_norm',)
+            )
+        
+        # Dominant pollen (below the air quality status, SHOW_POLLEN only)
+        self.canvas.create_text(
             0, 0,
-            window=self.aqi_canvas,
+            text="",
+            font=('IBM Plex Mono', 18, 'bold italic'),
+            fill='#FFFFFF',
             anchor='center',
-            tags=('aqi_slider',)
+            state='normal' if SHOW_POLLEN else 'hidden',
+            tags=('pollen',)
         )
         
         # Store image references
         self.aqi_bar_images = {}
         self.aqi_indicator_image = None
+        self._aqi_bar_source = None  # Full bar artwork kept in memory for resizes
         
         # === SECTION 3: TRANSPORT SCHEDULE ===
         # Headers
@@ -189,7 +1857,7 @@ class WeatherDisplay:
             font=('IBM Plex Mono', 24, 'bold italic'),
             fill='#FFFFFF',
             anchor='w',
-            tags=('transport_header_linie',)
+            tags=('transport_header_linie', 'transport')
         )
         
         self.canvas.create_text(
@@ -198,7 +1866,7 @@ class WeatherDisplay:
             font=('IBM Plex Mono', 24, 'bold italic'),
             fill='#FFFFFF',
             anchor='center',
-            tags=('transport_header_wann',)
+            tags=('transport_header_wann', 'transport')
         )
         
         self.canvas.create_text(
@@ -207,7 +1875,7 @@ class WeatherDisplay:
             font=('IBM Plex Mono', 24, 'bold italic'),
             fill='#FFFFFF',
             anchor='e',
-            tags=('transport_header_nach',)
+            tags=('transport_header_nach', 'transport')
         )
         
         # Row 1: S42
@@ -217,7 +1885,7 @@ class WeatherDisplay:
             font=('IBM Plex Mono', 40, 'bold italic'),
             fill='#FFFFFF',
             anchor='w',
-            tags=('transport_row1_linie',)
+            tags=('transport_row1_linie', 'transport')
         )
         
         self.canvas.create_text(
@@ -226,7 +1894,7 @@ class WeatherDisplay:
             font=('IBM Plex Mono', 40, 'bold italic'),
             fill='#FFFFFF',
             anchor='center',
-            tags=('transport_row1_wann',)
+            tags=('transport_row1_wann', 'transport')
         )
         
         self.canvas.create_text(
@@ -235,7 +1903,7 @@ class WeatherDisplay:
             font=('IBM Plex Mono', 20, 'bold italic'),
             fill='#FFFFFF',
             anchor='e',
-            tags=('transport_row1_nach',)
+            tags=('transport_row1_nach', 'transport')
         )
         
         # Row 2: S41
@@ -245,7 +1913,7 @@ class WeatherDisplay:
             font=('IBM Plex Mono', 40, 'bold italic'),
             fill='#FFFFFF',
             anchor='w',
-            tags=('transport_row2_linie',)
+            tags=('transport_row2_linie', 'transport')
         )
         
         self.canvas.create_text(
@@ -254,7 +1922,7 @@ class WeatherDisplay:
             font=('IBM Plex Mono', 40, 'bold italic'),
             fill='#FFFFFF',
             anchor='center',
-            tags=('transport_row2_wann',)
+            tags=('transport_row2_wann', 'transport')
         )
         
         self.canvas.create_text(
@@ -263,7 +1931,55 @@ class WeatherDisplay:
             font=('IBM Plex Mono', 20, 'bold italic'),
             fill='#FFFFFF',
             anchor='e',
-            tags=('transport_row2_nach',)
+            tags=('transport_row2_nach', 'transport')
+        )
+
+        if not SHOW_TRANSPORT:
+            self.canvas.itemconfig('transport', state='hidden')
+
+        # Today's summary sentence (bottom center, above the health line; SHOW_SUMMARY only)
+        self.canvas.create_text(
+            0, 0,
+            text="",
+            font=('IBM Plex Mono', 16, 'bold italic'),
+            fill='#FFFFFF',
+            anchor='s',
+            state='hidden',
+            tags=('summary',)
+        )
+
+        # Fetch in progress (bottom-left corner, only while a request is in flight)
+        self.canvas.create_text(
+            0, 0,
+            text="Updating…",
+            font=('IBM Plex Mono', 10, 'italic'),
+            fill='#FFFFFF',
+            anchor='sw',
+            state='hidden',
+            tags=('fetching',)
+        )
+
+        # Data source attribution (bottom-right corner, follows the provider actually in use)
+        self.canvas.create_text(
+            0, 0,
+            text="",
+            font=('IBM Plex Mono', 10, 'italic'),
+            fill='#FFFFFF',
+            anchor='se',
+            state='normal' if SHOW_ATTRIBUTION else 'hidden',
+            tags=('attribution',)
+        )
+        self.update_attribution()
+
+        # Data source health (bottom center; always with SHOW_HEALTH, otherwise only while a source fails)
+        self.canvas.create_text(
+            0, 0,
+            text="",
+            font=('IBM Plex Mono', 12, 'italic'),
+            fill='#FFFFFF',
+            anchor='s',
+            state='normal' if SHOW_HEALTH else 'hidden',
+            tags=('health',)
         )
 
         # Gradient demo button (top-right corner, only in debug mode)
@@ -280,9 +1996,12 @@ class WeatherDisplay:
 
         # Bind resize event
         self.canvas.bind('<Configure>', self.on_resize)
+        if NIGHT_FACE and NIGHT_FACE_WAKE_SECONDS:
+            self.canvas.bind('<Button-1>', lambda e: self.wake_screen('tap'))
         
         # Load AQI slider assets
-        self.load_aqi_assets()
+        if AIR_QUALITY_ENABLED:
+            self.load_aqi_assets()
     
     def on_resize(self, event=None):
         """Handle window resize to reposition widgets (debounced for performance)"""
@@ -306,11 +2025,44 @@ class WeatherDisplay:
         # === SECTION 1: HEADER (Top) ===
         header_y = height * 0.12
         
-        # Time (left-aligned)
-        self.canvas.coords('datetime', margin, header_y)
+        # Time (corner set by CLOCK_POSITION)
+        clock_on_right = CLOCK_POSITION.endswith('right')
+        clock_y = header_y if CLOCK_POSITION.startswith('top') else height * 0.92
+        clock_x = width - margin if clock_on_right else margin
+        self.canvas.coords('datetime', clock_x, clock_y)
+        self.canvas.itemconfig('datetime', anchor='e' if clock_on_right else 'w')
+        # Sun countdown sits below a top clock, above a bottom one
+        countdown_y = clock_y + 80 if CLOCK_POSITION.startswith('top') else clock_y - 80
+        self.canvas.coords('sun_countdown', clock_x, countdown_y)
+        self.canvas.itemconfig('sun_countdown', anchor='e' if clock_on_right else 'w')
+        rain_y = countdown_y + 30 if CLOCK_POSITION.startswith('top') else countdown_y - 30
+        self.canvas.coords('next_rain', clock_x, rain_y)
+        self.canvas.itemconfig('next_rain', anchor='e' if clock_on_right else 'w')
+        forecast_y = rain_y + 30 if CLOCK_POSITION.startswith('top') else rain_y - 30
+        self.canvas.coords('forecast_time', clock_x, forecast_y)
+        self.canvas.itemconfig('forecast_time', anchor='e' if clock_on_right else 'w')
+        
+        self.canvas.coords('location', width // 2, height * 0.02)
         
-        # Temperature (right-aligned)
-        self.canvas.coords('temperature', width - margin, header_y)
+        # Temperature and condition (right-aligned, move left when the clock takes the top-right corner)
+        if SHOW_CLOCK and CLOCK_POSITION == 'top-right':
+            temp_x, temp_anchor = margin, 'w'
+        else:
+            temp_x, temp_anchor = width - margin, 'e'
+        self.canvas.coords('temperature', temp_x, header_y)
+        self.canvas.itemconfig('temperature', anchor=temp_anchor)
+        self.canvas.coords('description', temp_x, header_y + 80)
+        self.canvas.itemconfig('description', anchor=temp_anchor)
+        self.canvas.coords('indoor_temperature', temp_x, header_y + 150)
+        self.canvas.itemconfig('indoor_temperature', anchor=temp_anchor)
+        self.canvas.coords('details', temp_x, header_y + 180)
+        self.canvas.itemconfig('details', anchor=temp_anchor)
+        self.canvas.coords('pressure', temp_x, header_y + 210)
+        self.canvas.itemconfig('pressure', anchor=temp_anchor)
+        spark_width = SPARKLINE_SIZE[0]
+        self._sparkline_box = (temp_x - spark_width if temp_anchor == 'e' else temp_x, header_y + 100)
+        self.draw_sparkline()
+        self.position_frost_badge()
         
         # === SECTION 2: AQI SLIDER (Middle) ===
         aqi_y = height * 0.40
@@ -319,6 +2071,12 @@ class WeatherDisplay:
         
         # Position the AQI canvas
         self.canvas.coords('aqi_slider', width // 2, aqi_y)
+        self.canvas.coords('aqi_status', width // 2, aqi_y + aqi_slider_height // 2 + 25)
+        norm_offset = 28 if SHOW_AQI_NORM else 0  # rows below make room for the norm line
+        self.canvas.coords('aqi_norm', width // 2, aqi_y + aqi_slider_height // 2 + 53)
+        self.canvas.coords('pollen', width // 2, aqi_y + aqi_slider_height // 2 + 55 + norm_offset)
+        self._pollutants_box = (width // 2, aqi_y + aqi_slider_height // 2 + 95 + norm_offset, aqi_slider_width)
+        self.draw_pollutants()
         
         # Resize AQI canvas
         if self.aqi_canvas:
@@ -351,9 +2109,18 @@ class WeatherDisplay:
         self.canvas.coords('transport_row2_wann', col2_x, row2_y)
         self.canvas.coords('transport_row2_nach', col3_x, row2_y)
         
+        self.canvas.coords('health', width // 2, height - 4)
+        self.canvas.coords('summary', width // 2, height - 24)
+        self._hourly_box = (width // 2, height - 100, int(width * 0.7))
+        self.draw_hourly()
+        self.canvas.coords('attribution', width - 6, height - 4)
+        self.canvas.coords('fetching', 6, height - 4)
+        
         # Position button
         if self.debug_enabled:
             self.canvas.coords('test_button', width - 10, 10)
+        
+        self.update_night_face()
     
     def _get_cached_font(self, family, size, weight='normal'):
         """Get a cached Font object to avoid repeated creation (expensive on Pi Zero)"""
@@ -432,8 +2199,24 @@ class WeatherDisplay:
         
         # Raise all UI elements above gradient
         self.canvas.tag_raise('temperature')
+        self.canvas.tag_raise('description')
+        self.canvas.tag_raise('frost_badge')
+        self.canvas.tag_raise('secondary_temperature')
+        self.canvas.tag_raise('sparkline')
+        self.canvas.tag_raise('indoor_temperature')
+        self.canvas.tag_raise('details')
+        self.canvas.tag_raise('pressure')
         self.canvas.tag_raise('datetime')
+        self.canvas.tag_raise('sun_countdown')
+        self.canvas.tag_raise('next_rain')
+        self.canvas.tag_raise('forecast_time')
+        self.canvas.tag_raise('location')
         self.canvas.tag_raise('aqi_slider')
+        self.canvas.tag_raise('aqi_status')
+        self.canvas.tag_raise('aqi_norm')
+        self.canvas.tag_raise('pollen')
+        self.canvas.tag_raise('pollutants')
+        self.canvas.tag_raise('hourly')
         self.canvas.tag_raise('transport_header_linie')
         self.canvas.tag_raise('transport_header_wann')
         self.canvas.tag_raise('transport_header_nach')
@@ -443,31 +2226,138 @@ class WeatherDisplay:
         self.canvas.tag_raise('transport_row2_linie')
         self.canvas.tag_raise('transport_row2_wann')
         self.canvas.tag_raise('transport_row2_nach')
+        self.canvas.tag_raise('health')
+        self.canvas.tag_raise('summary')
+        self.canvas.tag_raise('attribution')
+        self.canvas.tag_raise('fetching')
         if self.debug_enabled:
             self.canvas.tag_raise('test_button')
+        if self.night_face:
+            self.canvas.tag_raise('night_face')
+            self.canvas.tag_raise('datetime')
+    
+    def update_brightness(self):
+        """Fade the backlight to the current phase's BRIGHTNESS_* level when the phase changes
+
+        A night-face wake uses the day level, so the woken layout is actually readable.
+        """
+        if self.set_backlight is None:
+            return
+        phase = self.get_time_phase()
+        if time.monotonic() < self._wake_until:
+            phase = 'day'
+        if phase == self._brightness_phase:
+            return
+        self._brightness_phase = phase
+        start, target = self.brightness, PHASE_BRIGHTNESS[phase]
+        print(f"[Brightness] {phase}: {round(start)}% -> {target}%")
+        if self._brightness_after_id is not None:
+            self.root.after_cancel(self._brightness_after_id)
+            self._brightness_after_id = None
+        
+        def step(i):
+            self.brightness = start + (target - start) * i / BRIGHTNESS_RAMP_STEPS
+            try:
+                self.set_backlight(self.brightness)
+            except OSError as e:
+                print(f"[Brightness] Can't write the backlight, giving up: {e}")
+                self.set_backlight = None
+                return
+            if i < BRIGHTNESS_RAMP_STEPS:
+                self._brightness_after_id = self.root.after(
+                    BRIGHTNESS_RAMP_SECONDS * 1000 // BRIGHTNESS_RAMP_STEPS, lambda: step(i + 1))
+            else:
+                self._brightness_after_id = None
+        
+        step(1)
+    
+    def wake_screen(self, reason):
+        """Leave the night face for NIGHT_FACE_WAKE_SECONDS; another wake pushes the return back"""
+        if not NIGHT_FACE or not NIGHT_FACE_WAKE_SECONDS or self.get_time_phase() != 'night':
+            return
+        print(f"[Night] Woken by {reason}, full layout for {NIGHT_FACE_WAKE_SECONDS}s")
+        self._wake_until = time.monotonic() + NIGHT_FACE_WAKE_SECONDS
+        if self._wake_after_id is not None:
+            self.root.after_cancel(self._wake_after_id)
+        self._wake_after_id = self.root.after(NIGHT_FACE_WAKE_SECONDS * 1000, self.wake_expired)
+        self.update_night_face()
+        self.update_brightness()
+    
+    def wake_expired(self):
+        self._wake_after_id = None
+        self.update_night_face()
+        self.update_brightness()
+    
+    def update_night_face(self):
+        """Switch between the normal layout and the NIGHT_FACE clock-only face"""
+        active = NIGHT_FACE and self.get_time_phase() == 'night' and time.monotonic() >= self._wake_until
+        if active != self.night_face:
+            print(f"[Night] {'Switching to' if active else 'Leaving'} the clock-only night face")
+        was_active, self.night_face = self.night_face, active
+        
+        self.canvas.delete('night_face')
+        # Embedded widgets always draw above canvas items, so they are hidden explicitly
+        self.canvas.itemconfig('aqi_slider', state='hidden' if active else 'normal')
+        if self.test_button:
+            self.canvas.itemconfig('test_button', state='hidden' if active else 'normal')
+        
+        if active:
+            width = self.canvas.winfo_width()
+            height = self.canvas.winfo_height()
+            self.canvas.create_rectangle(
+                0, 0, width, height,
+                fill=NIGHT_FACE_BACKGROUND, outline='',
+                tags=('night_face',)
+            )
+            self.canvas.coords('datetime', width // 2, height // 2)
+            self.canvas.itemconfig('datetime', anchor='center', state='normal', fill=NIGHT_FACE_CLOCK_COLOR,
+                                   font=('IBM Plex Mono', 160, 'bold italic'))
+            self.canvas.tag_raise('night_face')
+            self.canvas.tag_raise('datetime')
+        elif was_active:
+            # Back to the regular clock; the resize pass puts it in its corner again
+            self.canvas.itemconfig('datetime', state='normal' if SHOW_CLOCK else 'hidden', fill='#FFFFFF',
+                                   font=('IBM Plex Mono', 90, 'bold italic'))
+            self.on_resize()
 
-    def get_time_phase(self):
+    def get_time_phase(self, now=None):
         if self.phase_override:
             return self.phase_override
-        h = datetime.now().hour
-        if h >= 21 or h < 5:
-            return 'night'
-        if 5 <= h < 8:
-            return 'sunrise'
-        if 17 <= h < 21:
-            return 'sunset'
-        return 'day'
+        now = now or local_now()
+        if PHASE_FROM_SUN:
+            return phase_from_sun_hours(now.hour + now.minute / 60, self.sun_hours_for(now))
+        return fixed_time_phase(now.hour)
 
+    def sun_hours_for(self, now):
+        """Today's (sunrise, sunset) hours: fetched times when known, else estimated from the latitude"""
+        for sunrise, sunset in self.sun_days:
+            if sunrise and sunset and sunrise.date() == now.date():
+                return sunrise.hour + sunrise.minute / 60, sunset.hour + sunset.minute / 60
+        
+        latitude = DEFAULT_LAT if self.latitude is None else self.latitude
+        longitude = DEFAULT_LON if self.longitude is None else self.longitude
+        # Solar noon on the local clock: timezone offset minus the longitude's 4 min/degree
+        utc_offset_hours = time.localtime().tm_gmtoff / 3600
+        return estimate_sun_hours(now.date(), latitude, 12 + utc_offset_hours - longitude / 15)
+    
     def compute_gradient(self, weather_code):
+        if FIXED_GRADIENT:
+            return FIXED_GRADIENT
+        start, end = self.base_gradient(weather_code)
+        
+        # Heat safety cue: dangerous apparent temperatures tint any palette toward red
+        if (HEAT_TINT and self.last_apparent_temp is not None
+                and self.last_apparent_temp > HEAT_TINT_THRESHOLD):
+            start = blend_rgb(start, (200, 40, 30), 0.5)
+            end = blend_rgb(end, (110, 15, 15), 0.5)
+        return start, end
+    
+    def base_gradient(self, weather_code):
         phase = self.get_time_phase()
 
         def rgb(hex_str):
             return int(hex_str[1:3], 16), int(hex_str[3:5], 16), int(hex_str[5:7], 16)
 
-        rain_codes = {51, 53, 55, 61, 63, 65, 80, 81, 82, 95, 96, 99}
-        snow_codes = {71, 73, 75, 77, 85, 86}
-        cloudy_codes = {2, 3, 45, 48}
-
         if phase == 'night':
             return rgb('#0b1d3a'), rgb('#0a1930')
         if phase == 'sunrise':
@@ -475,12 +2365,15 @@ class WeatherDisplay:
         if phase == 'sunset':
             return rgb('#ff9f68'), rgb('#2e1a47')
 
-        # day by weather
-        if weather_code in rain_codes:
+        # day by weather; an exact-code override beats the category palette
+        if weather_code in GRADIENT_OVERRIDES:
+            return GRADIENT_OVERRIDES[weather_code]
+        category = weather_code_category(weather_code)
+        if category == 'rain':
             return rgb('#5b4b8a'), rgb('#3c2f58')
-        if weather_code in snow_codes:
+        if category == 'snow':
             return rgb('#a8c0ff'), rgb('#3f2b96')
-        if weather_code in cloudy_codes:
+        if category == 'cloudy':
             return rgb('#7f8da1'), rgb('#546377')
         return rgb('#4da3ff'), rgb('#2b6fd6')
 
@@ -551,14 +2444,62 @@ class WeatherDisplay:
 
         run_stage(0)
     
+    def run_demo_cycle(self, step=0):
+        """Cycle simulated conditions offline (--demo) to exercise every visual branch"""
+        # (weather_code, temperature, CAQI) covering clear, cloudy, fog, rain, snow and storms
+        conditions = [
+            (0, 24, 12),
+            (0, 38, 20),
+            (2, 18, 40),
+            (3, 12, 58),
+            (45, 6, 75),
+            (61, 9, 90),
+            (65, 11, 120),
+            (80, 15, 66),
+            (71, -2, 30),
+            (86, -7, 45),
+            (95, 21, 160),
+        ]
+        phases = ['night', 'sunrise', 'day', 'sunset']
+        
+        code, temp, caqi = conditions[step % len(conditions)]
+        # Different cycle lengths so every condition is eventually seen in every phase
+        self.phase_override = phases[step % len(phases)]
+        print(f"[Demo] phase={self.phase_override} code={code} temp={temp} caqi={caqi}")
+        
+        self.update_weather_display({'current': {
+            'temperature_2m': temp,
+            'apparent_temperature': temp,
+            'weather_code': code,
+        }})
+        if AIR_QUALITY_ENABLED:
+            self.show_aqi(caqi)
+        
+        self.root.after(DEMO_STEP_INTERVAL * 1000, lambda: self.run_demo_cycle(step + 1))
+    
     def load_aqi_assets(self):
         """Load AQI slider images from assets folder"""
         try:
             from PIL import Image, ImageTk
             
-            # Load full bar image
-            self.aqi_bar_images['full'] = ImageTk.PhotoImage(Image.open('assets/bar_full.png'))
-            self.aqi_indicator_image = ImageTk.PhotoImage(Image.open('assets/bar_indicator.png'))
+            print(f"[AQI] Assets directory: {ASSETS_DIR} (resolved: {os.path.realpath(ASSETS_DIR)})")
+            print(f"[AQI] Cache directory: {CACHE_DIR}")
+            validate_icon_set()
+            
+            # Read the artwork into memory once; resizes reuse it instead of reopening the files
+            self._aqi_bar_source = open_asset('bar_full.png')
+            self._aqi_bar_source.load()
+            if DISPLAY_MODE == 'mono':
+                self._aqi_bar_source = monochrome(self._aqi_bar_source)
+            self.aqi_bar_images['full'] = ImageTk.PhotoImage(self._aqi_bar_source)
+            indicator = open_asset('bar_indicator.png')
+            if ICON_SCALE != 1.0:
+                size = (max(1, round(indicator.width * ICON_SCALE)), max(1, round(indicator.height * ICON_SCALE)))
+                indicator = indicator.resize(size, Image.LANCZOS)
+                print(f"[AQI] Indicator scaled by {ICON_SCALE} to {size[0]}x{size[1]}")
+            if DISPLAY_MODE == 'mono':
+                indicator = monochrome(indicator)
+            self.aqi_indicator_image = ImageTk.PhotoImage(indicator)
             
             print("[AQI] Assets loaded successfully")
         except Exception as e:
@@ -591,9 +2532,8 @@ class WeatherDisplay:
             try:
                 from PIL import Image, ImageTk
                 
-                # Load and resize bar_full.png to fill entire canvas width
-                full_img = Image.open('assets/bar_full.png')
-                full_resized = full_img.resize((canvas_width, canvas_height), Image.BILINEAR)
+                # Resize the in-memory bar_full.png to fill entire canvas width
+                full_resized = self._aqi_bar_source.resize((canvas_width, canvas_height), Image.BILINEAR)
                 self.aqi_bar_images['full_resized'] = ImageTk.PhotoImage(full_resized)
                 
                 # Place full bar spanning the entire canvas (left edge = 0, right edge = 100)
@@ -636,20 +2576,45 @@ class WeatherDisplay:
                 fill='white', width=4,
                 tags=('indicator',)
             )
+        
+        self.apply_aqi_availability()
+    
+    def set_aqi_available(self, available):
+        """Switch between the live bar and a greyed-out "no data" bar"""
+        if available != self.aqi_available:
+            print(f"[AQI] Air quality {'available' if available else 'unavailable'}")
+        self.aqi_available = available
+        self.apply_aqi_availability()
+    
+    def apply_aqi_availability(self):
+        """Grey out the bar and hide the indicator while there is no AQI data"""
+        if not self.aqi_canvas:
+            return
+        self.aqi_canvas.delete('unavailable')
+        if self.aqi_available:
+            self.aqi_canvas.itemconfig('indicator', state='normal')
+            return
+        self.aqi_canvas.itemconfig('indicator', state='hidden')
+        self.aqi_canvas.create_rectangle(
+            0, 0, self.aqi_canvas.winfo_width(), self.aqi_canvas.winfo_height(),
+            fill='#808080', stipple='gray75', outline='',
+            tags=('unavailable',)
+        )
     
     def update_aqi(self, caqi_value):
         """Update AQI indicator position based on CAQI value
         
         CAQI is inverted:
-        - CAQI 100+ = bad air = indicator at LEFT (0%)
-        - CAQI ~50-75 = medium = indicator in MIDDLE
-        - CAQI 0-25 = good air = indicator at RIGHT (100%)
+        - CAQI AQI_BAR_MAX+ = bad air = indicator at LEFT (0%)
+        - CAQI around half of AQI_BAR_MAX = medium = indicator in MIDDLE
+        - CAQI 0 = good air = indicator at RIGHT (100%)
         
         Args:
-            caqi_value: Raw CAQI value (0 to 100+)
+            caqi_value: Raw CAQI value (0 and up)
         """
-        # Store the value for re-applying after resize
+        # Store the true value (for re-applying after resize) and the clamped one the bar shows
         self.current_caqi_value = caqi_value
+        self.aqi_bar_value = max(0, min(AQI_BAR_MAX, caqi_value))
         
         if not self.aqi_canvas:
             print("[AQI] No aqi_canvas available")
@@ -664,16 +2629,16 @@ class WeatherDisplay:
             self.root.after(500, lambda: self.update_aqi(caqi_value))
             return
         
-        # Clamp CAQI to 0-100 range, then invert
-        # CAQI 0 -> position 100% (right), CAQI 100+ -> position 0% (left)
-        clamped_caqi = max(0, min(100, caqi_value))
-        position_percent = 100 - clamped_caqi
+        # Scale the clamped CAQI to 0-100%, then invert
+        # CAQI 0 -> position 100% (right), CAQI AQI_BAR_MAX+ -> position 0% (left)
+        clamped_caqi = self.aqi_bar_value
+        position_percent = 100 - clamped_caqi / AQI_BAR_MAX * 100
         
         # Calculate X position - position_percent maps to canvas width
         # 0% = left edge, 100% = right edge
         x_pos = int((position_percent / 100) * canvas_width)
         
-        print(f"[AQI] CAQI: {caqi_value} -> clamped: {clamped_caqi} -> position: {position_percent}% -> x_pos: {x_pos} (canvas_width: {canvas_width})")
+        print(f"[AQI] CAQI: {caqi_value} -> clamped: {clamped_caqi} -> position: {position_percent:.0f}% -> x_pos: {x_pos} (canvas_width: {canvas_width})")
         
         # Move indicator
         indicator = self.aqi_canvas.find_withtag('indicator')
@@ -689,104 +2654,412 @@ class WeatherDisplay:
             print("[AQI] No indicator found on canvas")
     
     def fetch_air_quality(self):
-        """Fetch air quality data from Airly API"""
-        print(f"[AQI] Fetching air quality data from Airly...")
-        print(f"[AQI] AIRLY_API_KEY: {'***' if AIRLY_API_KEY else 'NOT SET'}")
-        print(f"[AQI] Location: {AIRLY_LATITUDE}, {AIRLY_LONGITUDE}")
+        """Fetch air quality from the providers in AQI_PROVIDERS order; the first with data wins"""
+        if not AIR_QUALITY_ENABLED:
+            return
+        fetch_id = new_fetch_id('aq')
+        self.set_fetching('aqi', True)
+        try:
+            self.query_aqi_providers(fetch_id)
+        finally:
+            self.set_fetching('aqi', False)
+    
+    def query_aqi_providers(self, fetch_id):
+        """Show the first AQI_PROVIDERS result, or the unavailable state when none has data"""
+        self._aqi_error_text = None
+        self.airly_key_invalid = False
+        self.pollutants = {}  # only Airly reports them; a fallback provider hides the bars
+        self.aqi_standards = []
+        providers = {
+            'airly': ('Airly', self.fetch_airly_aqi),
+            'open-meteo': ('Open-Meteo', self.fetch_open_meteo_aqi),
+        }
+        for key in AQI_PROVIDERS:
+            name, fetch = providers[key]
+            try:
+                aqi_score = fetch(fetch_id)
+            except Exception as e:
+                print(f"[{fetch_id}] [AQI] Error fetching air quality from {name}: {e}")
+                import traceback
+                traceback.print_exc()
+                aqi_score = None
+            
+            if aqi_score is None:
+                print(f"[{fetch_id}] [AQI] {name} returned no data, trying next provider")
+                continue
+            
+            print(f"[{fetch_id}] [AQI] Air quality served by {name}")
+            self.aqi_provider = name
+            self.update_attribution()
+            self.show_aqi(aqi_score)
+            self.draw_pollutants()
+            self.update_aqi_norm()
+            return
+        
+        print(f"[{fetch_id}] [AQI] No provider returned air quality data")
+        self.aqi_failed = True
+        self.update_health_line()
+        self.draw_pollutants()
+        self.update_aqi_norm()
+        self.set_aqi_available(False)
+        self.canvas.itemconfig('aqi_status', text=truncate_with_ellipsis(self._aqi_error_text or "AQI unavailable"))
+    
+    def set_fetching(self, source, active):
+        """Track in-flight fetches and show "Updating…" while any is running
+
+        Foreground fetches block the Tk loop, so the indicator is drawn
+        before the request starts rather than at the next idle moment.
+        """
+        if active:
+            self._fetching.add(source)
+        else:
+            self._fetching.discard(source)
+        if not SHOW_FETCH_INDICATOR:
+            return
+        self.canvas.itemconfig('fetching', state='normal' if self._fetching else 'hidden')
+        if active:
+            self.canvas.update_idletasks()
+    
+    def show_aqi(self, aqi_score):
+        """Apply an AQI score to the slider, status text and alert output"""
+        print(f"[AQI] Updating slider with score: {aqi_score}")
+        self.set_aqi_available(True)
+        self.aqi_failed = False
+        self.update_aqi(round(aqi_score))
+        self.aqi_history.append((local_now(), self.aqi_provider, aqi_score))
+        self.aqi_deteriorating = aqi_rising(list(self.aqi_history))
+        status = self.aqi_status_text(aqi_score)
+        if self.aqi_deteriorating:
+            (_, _, previous), _ = self.aqi_history
+            print(f"[AQI] Air quality deteriorating: {previous} -> {aqi_score}")
+            status += " · Deteriorating"
+        self.canvas.itemconfig('aqi_status', text=truncate_with_ellipsis(status))
+        self.update_snapshot(caqi=aqi_score, aqi_status=status)
+        was_alert, self.aqi_alert = self.aqi_alert, aqi_score > HAZARDOUS_CAQI
+        if self.aqi_alert and not was_alert:
+            self.wake_screen('hazardous air alert')
+        self.update_alert_output()
+        print(f"[AQI] Air quality updated successfully")
+        self.aqi_updated_at = local_now()
+        self.update_staleness()
+        self.update_summary()
+    
+    def update_attribution(self):
+        """Credit the weather provider and the air quality provider that served the last value"""
+        parts = ["Weather: Open-Meteo"]
+        if AIR_QUALITY_ENABLED and self.aqi_provider:
+            parts.append(f"Air: {self.aqi_provider}")
+        self.canvas.itemconfig('attribution', text=" · ".join(parts))
+    
+    def update_summary(self):
+        """Refresh the summary sentence from the last weather response and air quality reading"""
+        if not SHOW_SUMMARY or self.last_weather_data is None:
+            return
+        air_quality = self.caqi_to_simple_status(self.current_caqi_value) if self.aqi_updated_at else None
+        text = summarize(self.last_weather_data, self.daily_forecast, air_quality)
+        self.canvas.itemconfig('summary', text=truncate_with_ellipsis(text or '', TEXT_MAX_CHARS * 2),
+                               state='normal' if text else 'hidden')
+    
+    def update_aqi_norm(self):
+        """Show the pollutant furthest over (or closest to) its norm; hidden without standards data"""
+        if not SHOW_AQI_NORM or not self.aqi_standards:
+            self.canvas.itemconfig('aqi_norm', state='hidden')
+            return
+        pollutant, percent = max(self.aqi_standards, key=lambda standard: standard[1])
+        label = POLLUTANT_LIMITS.get(pollutant, (pollutant,))[0]
+        self.canvas.itemconfig('aqi_norm', text=f"{label} at {format_number(round(percent))}% of norm", state='normal')
+    
+    def draw_pollutants(self):
+        """One labelled bar per pollutant in POLLUTANT_LIMITS that Airly reported; others are left out"""
+        self.canvas.delete('pollutants')
+        readings = [(key, self.pollutants[key]) for key in POLLUTANT_LIMITS if key in self.pollutants]
+        if not SHOW_POLLUTANTS or not readings or self._pollutants_box is None:
+            return
+        
+        center_x, top, total_width = self._pollutants_box
+        bar_width, bar_height = POLLUTANT_BAR_SIZE
+        column = total_width / len(readings)
+        state = 'hidden' if self.night_face else 'normal'
+        for i, (key, value) in enumerate(readings):
+            label, limit = POLLUTANT_LIMITS[key]
+            x = center_x - total_width / 2 + column * (i + 0.5)
+            left = x - bar_width / 2
+            self.canvas.create_text(
+                x, top, text=f"{label} {format_concentration(value, limit)}",
+                font=('IBM Plex Mono', 14, 'bold italic'), fill='#FFFFFF',
+                anchor='s', state=state, tags=('pollutants',)
+            )
+            self.canvas.create_rectangle(
+                left, top + 4, left + bar_width, top + 4 + bar_height,
+                outline='#FFFFFF', state=state, tags=('pollutants',)
+            )
+            fill_width = bar_width * min(1.0, value / limit)
+            if fill_width >= 1:
+                self.canvas.create_rectangle(
+                    left, top + 4, left + fill_width, top + 4 + bar_height,
+                    fill=pollutant_color(value, limit), outline='', state=state, tags=('pollutants',)
+                )
+    
+    def fetch_open_meteo_aqi(self, fetch_id):
+        """European AQI from Open-Meteo's air-quality API (no key needed), or None
+        
+        The European AQI runs on a 0-100+ scale close enough to CAQI to drive
+        the same slider and status bands.
+        """
+        lat = self.aqi_latitude or self.latitude
+        lon = self.aqi_longitude or self.longitude
+        if lat is None or lon is None:
+            return None
+        
+        url = open_meteo_url(
+            OPEN_METEO_AIR_QUALITY_URL, "/v1/air-quality",
+            f"latitude={lat}&longitude={lon}"
+            f"&current=european_aqi"
+            f"&timezone=auto"
+        )
+        response = self.cached_get('open-meteo/aqi', lat, lon, url, fetch_id=fetch_id, timeout=10)
+        value = (response.json().get('current') or {}).get('european_aqi')
+        if value is None:
+            return None
+        print(f"[{fetch_id}] [AQI] Open-Meteo European AQI: {value}")
+        return value  # raw, for AQI_NUMBER_DECIMALS; show_aqi rounds it for the bar
+    
+    def fetch_airly_aqi(self, fetch_id):
+        """Score from the preferred Airly index, or None when Airly has no data"""
+        print(f"[{fetch_id}] [AQI] Fetching air quality data from Airly...")
+        print(f"[{fetch_id}] [AQI] AIRLY_API_KEY: {'***' if AIRLY_API_KEY else 'NOT SET'}")
+        print(f"[{fetch_id}] [AQI] Location: {self.aqi_latitude}, {self.aqi_longitude}")
+        
+        if not AIRLY_API_KEY or (not AIRLY_INSTALLATION_ID and (not self.aqi_latitude or not self.aqi_longitude)):
+            print(f"[{fetch_id}] [AQI] Warning: AIRLY_API_KEY, AIRLY_LATITUDE, or AIRLY_LONGITUDE not configured in .env")
+            return None
+
+        headers = {"apikey": AIRLY_API_KEY}
+        
+        # Step 1: Use the pinned installation, or look up the nearest one
+        if AIRLY_INSTALLATION_ID:
+            installation_id = AIRLY_INSTALLATION_ID
+            print(f"[{fetch_id}] [AQI] Step 1: Using pinned installation ID: {installation_id}")
+        else:
+            installation_id = self.find_named_installation(headers, fetch_id) if AIRLY_INSTALLATION_NAME else None
+            if installation_id is None:
+                installation_id = self.find_nearest_installation(headers, fetch_id)
+            if installation_id is None:
+                return None
+        
+        # Step 2: Get measurements for the closest installation
+        url_measurements = f"https://airapi.airly.eu/v2/measurements/installation?installationId={installation_id}&includeWildcards=true"
+        print(f"[{fetch_id}] [AQI] Step 2: Fetching measurements for installation {installation_id}...")
+        
+        response = self.cached_get('airly/measurements', self.aqi_latitude, self.aqi_longitude,
+                                   url_measurements, fetch_id=fetch_id, headers=headers, timeout=10)
+        print(f"[{fetch_id}] [AQI] Response status: {response.status_code}")
+        if self.airly_key_rejected(response, fetch_id):
+            return None
+        
+        data = response.json()
+        print(f"[{fetch_id}] [AQI] Response data keys: {list(data.keys()) if isinstance(data, dict) else 'not a dict'}")
+
+        parsed = parse_airly_current(data)
+        if parsed is None:
+            print(f"[{fetch_id}] [AQI] Unexpected Airly response shape (no usable 'current'): {str(data)[:300]}")
+            return None
+        
+        # Keep every index so the full set is available, not just CAQI
+        self.aqi_indexes, pollutants = parsed
+        self.pollutants = pollutants
+        self.aqi_standards = parse_airly_standards(data)
+        print(f"[{fetch_id}] [AQI] Indexes: {self.aqi_indexes}")
+        print(f"[{fetch_id}] [AQI] Values: {pollutants}")
+        
+        # Pick the first available index in priority order
+        aqi_score = 0
+        self.aqi_index_used = None
+        for name in AIRLY_INDEX_PRIORITY:
+            if name in self.aqi_indexes:
+                aqi_score = self.aqi_indexes[name]
+                self.aqi_index_used = name
+                break
+            if name == 'PM25' and 'PM25' in pollutants:
+                pm25_value = round(pollutants['PM25'], 1)
+                print(f"[{fetch_id}] [AQI] PM2.5: {format_number(pm25_value, 1)} µg/m³")
+                # Convert PM2.5 to 0-100 scale (rough approximation)
+                aqi_score = min(100, int(pm25_value * 0.5))
+                self.aqi_index_used = name
+                break
+        
+        if not self.aqi_index_used:
+            print(f"[{fetch_id}] [AQI] None of {AIRLY_INDEX_PRIORITY} found in response")
+            return None
+        print(f"[{fetch_id}] [AQI] Using {self.aqi_index_used} -> score {aqi_score}")
+        return aqi_score
+    
+    def cached_get(self, endpoint, lat, lon, url, fetch_id=None, **kwargs):
+        """GET with a short in-memory TTL cache keyed by (endpoint, lat, lon)
+        
+        Protects API quota when fetches are triggered back-to-back. Only
+        successful responses are cached. Callable from the fetch worker
+        (pass its own session=); the request itself runs outside the lock.
+        """
+        key = (endpoint, lat, lon)
+        now = time.monotonic()
+        with self._fetch_cache_lock:
+            cached = self._fetch_cache.get(key)
+        if cached and now - cached[0] < FETCH_CACHE_TTL:
+            if self.debug_enabled:
+                print(f"{f'[{fetch_id}] ' if fetch_id else ''}[Cache] Hit for {endpoint} ({lat}, {lon}), age {int(now - cached[0])}s")
+            return cached[1]
         
-        if not AIRLY_API_KEY or not AIRLY_LATITUDE or not AIRLY_LONGITUDE:
-            print("[AQI] Warning: AIRLY_API_KEY, AIRLY_LATITUDE, or AIRLY_LONGITUDE not configured in .env")
+        response = http_get(url, **kwargs)
+        if response.ok and FETCH_CACHE_TTL > 0:
+            with self._fetch_cache_lock:
+                self._fetch_cache[key] = (now, response)
+        return response
+    
+    def update_alert_output(self):
+        """Mirror the freeze/air alerts onto the GPIO pin (no-op without one)"""
+        if self.alert_output is None:
             return
-
-        try:
-            # Step 1: Get nearest installations
-            url_installations = f"https://airapi.airly.eu/v2/installations/nearest?lat={AIRLY_LATITUDE}&lng={AIRLY_LONGITUDE}&maxDistanceKM={AIRLY_MAX_DISTANCE_KM}&maxResults=3"
-            headers = {"apikey": AIRLY_API_KEY}
-            print(f"[AQI] Step 1: Fetching nearest installations...")
-            print(f"[AQI] URL: {url_installations}")
-            
-            response = requests.get(url_installations, headers=headers, timeout=10)
-            print(f"[AQI] Response status: {response.status_code}")
-            
-            installations = response.json()
-            print(f"[AQI] Found {len(installations)} installations")
-            
-            if not installations or len(installations) == 0:
-                raise Exception('No installations found')
-            
-            # Get the closest installation
-            closest_installation = installations[0]
-            installation_id = closest_installation.get('id')
-            print(f"[AQI] Using installation ID: {installation_id}")
-            print(f"[AQI] Address: {closest_installation.get('address', {}).get('displayAddress1', 'Unknown')}")
-            
-            # Step 2: Get measurements for the closest installation
-            url_measurements = f"https://airapi.airly.eu/v2/measurements/installation?installationId={installation_id}&includeWildcards=true"
-            print(f"[AQI] Step 2: Fetching measurements for installation {installation_id}...")
-            
-            response = requests.get(url_measurements, headers=headers, timeout=10)
-            print(f"[AQI] Response status: {response.status_code}")
-            
-            data = response.json()
-            print(f"[AQI] Response data keys: {list(data.keys()) if isinstance(data, dict) else 'not a dict'}")
-
-            if data.get('current'):
-                current = data['current']
-                indexes = current.get('indexes', [])
-                values = current.get('values', [])
-                
-                print(f"[AQI] Indexes found: {len(indexes)}")
-                print(f"[AQI] Values found: {len(values)}")
-                
-                # Extract AIRLY_CAQI or PM2.5
-                aqi_score = 0
-                for index in indexes:
-                    if index.get('name') == 'AIRLY_CAQI':
-                        caqi_value = round(index.get('value', 0))
-                        aqi_score = caqi_value
-                        print(f"[AQI] Found CAQI index: {caqi_value}")
-                        break
-                
-                # Fallback to PM2.5 if CAQI not found
-                if aqi_score == 0:
-                    for value in values:
-                        if value.get('name') == 'PM25':
-                            pm25_value = round(value.get('value', 0), 1)
-                            # Convert PM2.5 to 0-100 scale (rough approximation)
-                            aqi_score = min(100, int(pm25_value * 0.5))
-                            print(f"[AQI] Found PM2.5: {pm25_value} -> score {aqi_score}")
-                            break
-                
-                # Update the slider
-                print(f"[AQI] Updating slider with score: {aqi_score}")
-                self.update_aqi(aqi_score)
-                print(f"[AQI] Air quality updated successfully")
+        active = self.alert_active or self.aqi_alert
+        if active != bool(self.alert_output.value):
+            print(f"[Alert] GPIO {'on' if active else 'off'} (freeze={self.alert_active}, air={self.aqi_alert})")
+            if active:
+                self.alert_output.on()
             else:
-                raise Exception('Air quality data not found in response')
-        except Exception as e:
-            print(f"[AQI] Error fetching air quality: {e}")
-            import traceback
-            traceback.print_exc()
+                self.alert_output.off()
+    
+    def find_nearest_installation(self, headers, fetch_id):
+        """Return the ID of the nearest Airly installation (None if the key was rejected)"""
+        url_installations = f"https://airapi.airly.eu/v2/installations/nearest?lat={self.aqi_latitude}&lng={self.aqi_longitude}&maxDistanceKM={AIRLY_MAX_DISTANCE_KM}&maxResults=3"
+        print(f"[{fetch_id}] [AQI] Step 1: Fetching nearest installations...")
+        print(f"[{fetch_id}] [AQI] URL: {url_installations}")
+        
+        response = self.cached_get('airly/nearest', self.aqi_latitude, self.aqi_longitude,
+                                   url_installations, fetch_id=fetch_id, headers=headers, timeout=10)
+        print(f"[{fetch_id}] [AQI] Response status: {response.status_code}")
+        if self.airly_key_rejected(response, fetch_id):
+            return None
+        
+        installations = response.json()
+        print(f"[{fetch_id}] [AQI] Found {len(installations)} installations")
+        
+        if not installations or len(installations) == 0:
+            raise Exception('No installations found')
+        
+        # Get the closest installation
+        closest_installation = installations[0]
+        installation_id = closest_installation.get('id')
+        print(f"[{fetch_id}] [AQI] Using installation ID: {installation_id}")
+        print(f"[{fetch_id}] [AQI] Address: {closest_installation.get('address', {}).get('displayAddress1', 'Unknown')}")
+        return installation_id
+    
+    def find_named_installation(self, headers, fetch_id):
+        """ID of the nearby installation matching AIRLY_INSTALLATION_NAME (cached once found), or None"""
+        if self._named_installation_id is not None:
+            return self._named_installation_id
+        url = (f"https://airapi.airly.eu/v2/installations/nearest?lat={self.aqi_latitude}&lng={self.aqi_longitude}"
+               f"&maxDistanceKM={AIRLY_NAME_SEARCH_KM}&maxResults=100")
+        print(f"[{fetch_id}] [AQI] Step 1: Looking up installation {AIRLY_INSTALLATION_NAME!r}...")
+        response = self.cached_get('airly/named', self.aqi_latitude, self.aqi_longitude,
+                                   url, fetch_id=fetch_id, headers=headers, timeout=10)
+        if self.airly_key_rejected(response, fetch_id):
+            return None
+        installations = response.json() or []
+        match = next((inst for inst in installations if installation_matches(inst, AIRLY_INSTALLATION_NAME)), None)
+        if match is None:
+            print(f"[{fetch_id}] [AQI] No installation within {AIRLY_NAME_SEARCH_KM} km matches "
+                  f"{AIRLY_INSTALLATION_NAME!r}, using the nearest one")
+            return None
+        self._named_installation_id = match.get('id')
+        print(f"[{fetch_id}] [AQI] {AIRLY_INSTALLATION_NAME!r} resolved to installation {self._named_installation_id} "
+              f"({(match.get('address') or {}).get('displayAddress1', 'Unknown')})")
+        return self._named_installation_id
+    
+    def airly_key_rejected(self, response, fetch_id):
+        """Report a 401/403 from Airly as a setup problem instead of a generic error"""
+        if response.status_code not in (401, 403):
+            return False
+        print(f"[{fetch_id}] [AQI] Airly API key rejected — check AIRLY_API_KEY")
+        self._aqi_error_text = "Airly API key rejected"
+        self.airly_key_invalid = True
+        self.canvas.itemconfig('aqi_status', text=self._aqi_error_text)
+        return True
+    
+    def aqi_status_text(self, caqi_value):
+        """Status line for the current AQI_SIMPLE / SHOW_AQI_NUMBER settings"""
+        if AQI_SIMPLE:
+            status = self.caqi_to_simple_status(caqi_value)
+        else:
+            status = self.caqi_to_status(caqi_value)
+        if SHOW_AQI_NUMBER:
+            # Open-Meteo serves the European AQI, not CAQI
+            index = 'EAQI' if self.aqi_provider == 'Open-Meteo' else 'CAQI'
+            status += f" · {index} {format_number(caqi_value, AQI_NUMBER_DECIMALS)}"
+        return status
+    
+    def caqi_to_simple_status(self, caqi_value):
+        """Collapse the CAQI bands of caqi_to_status into Good/Moderate/Bad"""
+        caqi = float(caqi_value)
+        if caqi <= AQI_BANDS.low:
+            return "Good"
+        elif caqi <= AQI_BANDS.medium:
+            return "Moderate"
+        else:
+            return "Bad"
     
     def caqi_to_status(self, caqi_value):
         """Convert CAQI value to verbal air quality status"""
         caqi = float(caqi_value)
-        if caqi <= 33:
+        if caqi <= AQI_BANDS.very_low:
             return "A-MAZE-BALLS"
-        elif caqi <= 66:
+        elif caqi <= AQI_BANDS.low:
             return "Open the windows, go out!"
-        elif caqi <= 99:
+        elif caqi <= AQI_BANDS.medium:
             return "It's ok..."
-        elif caqi <= 150:
+        elif caqi <= AQI_BANDS.high:
             return "Bad, but will survive"
         else:
             return "Hazardous, do not open the windows"
     
+    def fetch_pollen(self, lat, lon):
+        """Return (pollen name, level) for the dominant pollen at lat/lon, or None"""
+        url = open_meteo_url(
+            OPEN_METEO_AIR_QUALITY_URL, "/v1/air-quality",
+            f"latitude={lat}&longitude={lon}"
+            f"&current={','.join(POLLEN_TYPES)}"
+            f"&timezone=auto"
+        )
+        response = self.cached_get('pollen', lat, lon, url, timeout=10)
+        current = response.json().get('current') or {}
+        
+        readings = {key: current.get(key) for key in POLLEN_TYPES if current.get(key) is not None}
+        if not readings:
+            return None
+        dominant = max(readings, key=readings.get)
+        return POLLEN_TYPES[dominant], pollen_level(readings[dominant])
+    
+    def update_pollen(self):
+        """Fetch pollen and show the dominant type in the pollen slot"""
+        if not SHOW_POLLEN or self.latitude is None or self.longitude is None:
+            return
+        try:
+            result = self.fetch_pollen(self.latitude, self.longitude)
+            if result is None:
+                print("[Pollen] No pollen data for this location")
+                self.canvas.itemconfig('pollen', text="Pollen: no data")
+                return
+            name, level = result
+            print(f"[Pollen] Dominant: {name} ({level})")
+            text = "Pollen: none" if level == 'none' else f"Pollen: {name} ({level})"
+            self.canvas.itemconfig('pollen', text=text)
+        except Exception as e:
+            print(f"[Pollen] Error fetching pollen: {e}")
+    
     def get_coordinates_from_city(self):
         """Get coordinates from city name using geocoding"""
         try:
             url = f"https://geocoding-api.open-meteo.com/v1/search?name={LOCATION['city']}&count=1&language=en&format=json"
-            response = requests.get(url, timeout=10)
+            response = http_get(url, timeout=10)
             data = response.json()
             
             if data.get('results') and len(data['results']) > 0:
@@ -802,64 +3075,707 @@ class WeatherDisplay:
                 raise Exception('Location not found')
         except Exception as e:
             print(f"Error getting coordinates: {e}")
-            # Fallback to Berlin
-            self.latitude = 52.52
-            self.longitude = 13.405
-            self.location_name = 'Berlin, Germany'
+            if DEFAULT_LOCATION:
+                self.latitude, self.longitude = DEFAULT_LOCATION
+                self.location_name = None  # named by resolve_location_name
+            else:
+                # Fallback to Berlin
+                self.latitude = DEFAULT_LAT
+                self.longitude = DEFAULT_LON
+                self.location_name = 'Berlin, Germany'
+            print(f"Using default location {self.latitude}, {self.longitude}")
+        
+        self.latitude, self.longitude = round_coordinate(self.latitude), round_coordinate(self.longitude)
+        print(f"[Location] Using coordinates {self.latitude}, {self.longitude} ({COORD_PRECISION} decimals)")
+        if self.location_name is not None:
+            self.canvas.itemconfig('location', text=self.location_name)
+    
+    def resolve_location_name(self):
+        """Name the current coordinates (disk cache, then reverse geocoding, else the coordinates)"""
+        key = f"{self.latitude:.2f},{self.longitude:.2f}"
+        try:
+            with open(cache_path(PLACE_NAMES_CACHE_FILE)) as f:
+                names = json.load(f)
+        except FileNotFoundError:
+            names = {}
+        except Exception as e:
+            print(f"[Cache] Could not read place names: {e}")
+            names = {}
         
+        if key in names:
+            self.location_name = names[key]
+        else:
+            try:
+                self.location_name = reverse_geocode(self.latitude, self.longitude)
+                print(f"[Location] {key} is {self.location_name}")
+                names[key] = self.location_name
+                path = cache_path(PLACE_NAMES_CACHE_FILE)
+                with open(path + '.tmp', 'w') as f:
+                    json.dump(names, f)
+                os.replace(path + '.tmp', path)
+            except Exception as e:
+                print(f"[Location] Reverse geocoding failed: {e}")
+                self.location_name = key.replace(',', ', ')
         self.canvas.itemconfig('location', text=self.location_name)
     
+    def update_location_from_gps(self):
+        """Use the gpsd fix for weather and AQI coordinates; keep the configured ones without a fix"""
+        fix = query_gpsd(GPSD_ADDR)
+        if fix is None:
+            print(f"[GPS] No fix within {GPSD_TIMEOUT}s, keeping coordinates {self.latitude}, {self.longitude}")
+            return False
+        
+        lat, lon = fix
+        print(f"[GPS] Fix: {lat:.5f}, {lon:.5f}")
+        lat, lon = round_coordinate(lat), round_coordinate(lon)
+        self.latitude, self.longitude = lat, lon
+        self.aqi_latitude, self.aqi_longitude = lat, lon
+        self.location_name = None  # the place name follows the fix
+        return True
+    
+    def schedule_gps_update(self):
+        """Refresh the GPS location periodically; the next scheduled fetches use it"""
+        if not self.ui_alive('gps'):
+            return
+        try:
+            self.update_location_from_gps()
+            if self.location_name is None and not self.is_quiet_time():
+                self.resolve_location_name()
+            self.check_relocation()
+        except Exception as e:
+            print(f"Error in GPS update: {e}")
+        self._gps_after_id = self.root.after(GPS_REFRESH_INTERVAL * 1000, self.schedule_gps_update)
+    
+    def check_relocation(self):
+        """Refetch for the new coordinates once a fix is RELOCATE_DISTANCE_KM from the last fetched location"""
+        if RELOCATE_DISTANCE_KM <= 0 or self._fetched_location is None:
+            return
+        moved = haversine_km(*self._fetched_location, self.latitude, self.longitude)
+        if moved < RELOCATE_DISTANCE_KM:
+            return
+        print(f"[GPS] Moved {format_number(moved, 1)} km since the last fetch, refetching for the new location")
+        self._fetched_location = (self.latitude, self.longitude)
+        with self._fetch_cache_lock:
+            self._fetch_cache.clear()
+        self.pressure_history.clear()  # a trend across two places means nothing
+        if not self.is_quiet_time():
+            self.refetch_all()
+    
+    def schedule_indoor_update(self):
+        """Read the indoor sensor periodically; a failed read keeps the last value"""
+        if not self.ui_alive('indoor sensor'):
+            return
+        try:
+            celsius = self.indoor_sensor()
+            if celsius is not None:
+                self.canvas.itemconfig('indoor_temperature', text=f"Indoor {format_temperature(celsius, '°C')}")
+        except Exception as e:
+            # DHT22 reads fail now and then (timing-sensitive); the next one usually works
+            print(f"[Indoor] Sensor read failed: {e}")
+        self._indoor_after_id = self.root.after(INDOOR_SENSOR_INTERVAL * 1000, self.schedule_indoor_update)
+    
     def fetch_weather(self):
         """Fetch weather data from Open-Meteo API"""
         if self.latitude is None or self.longitude is None:
             return
+        self._last_weather_attempt = time.monotonic()
+        fetch_id = new_fetch_id('wx')
+        
+        if BACKGROUND_FETCH:
+            if self._weather_in_flight:
+                print(f"[{fetch_id}] [Weather] Previous background fetch still running, skipping")
+                return
+            self.start_fetch_worker()
+            self._weather_in_flight = True
+            self.set_fetching('weather', True)
+            self._fetch_jobs.put((self.latitude, self.longitude, fetch_id, self.weather_base_url(fetch_id)))
+            return
         
+        self.set_fetching('weather', True)
         try:
-            url = (
-                f"https://api.open-meteo.com/v1/forecast?"
-                f"latitude={self.latitude}&longitude={self.longitude}"
-                f"&current=temperature_2m,weather_code"
-                f"&timezone=auto"
-            )
-            
-            response = requests.get(url, timeout=10)
-            data = response.json()
-            
-            self.update_weather_display(data)
+            data = self.request_weather(self.latitude, self.longitude, fetch_id, self.weather_base_url(fetch_id))
+            self.apply_weather(data, fetch_id)
         except Exception as e:
-            print(f"Error fetching weather: {e}")
+            self.weather_fetch_failed(e, fetch_id)
+    
+    def weather_base_url(self, fetch_id):
+        """Forecast server for the next fetch (Tk thread; the worker gets the result in its job)"""
+        # Persistent failure: alternate with the backup host, so the primary's recovery is still noticed
+        if OPEN_METEO_BACKUP_URL and self.weather_failures >= FETCH_FAILURE_THRESHOLD \
+                and (self.weather_failures - FETCH_FAILURE_THRESHOLD) % 2 == 0:
+            print(f"[{fetch_id}] [Weather] {self.weather_failures} failures in a row, trying backup host {OPEN_METEO_BACKUP_URL}")
+            return OPEN_METEO_BACKUP_URL
+        return OPEN_METEO_URL
+    
+    def request_weather(self, lat, lon, fetch_id, base, session=None):
+        """Network half of fetch_weather: returns the parsed response, raises on failure
+        
+        Reads no mutable display state (the caller picks base) and touches no
+        Tk state; from the fetch worker, pass the worker's own session.
+        """
+        url = open_meteo_url(
+            base, "/v1/forecast",
+            f"latitude={lat}&longitude={lon}"
+            f"&current=temperature_2m,apparent_temperature,weather_code"
+            f"{',wind_speed_10m,wind_gusts_10m' if SHOW_WIND else ''}"
+            f"{',relative_humidity_2m' if SHOW_HUMIDITY or SHOW_COMFORT else ''}"
+            f"{',pressure_msl' if SHOW_PRESSURE else ''}"
+            f"&temperature_unit={REQUESTED_UNITS['temperature_2m'][1]}"
+            f"{'&wind_speed_unit=' + REQUESTED_UNITS['wind_speed_10m'][1] if SHOW_WIND else ''}"
+            f"&hourly=precipitation{',precipitation_probability' if SHOW_NEXT_RAIN else ''}"
+            f"{',temperature_2m,weather_code' if FORECAST_TIME or SHOW_HOURLY else ''}"
+            f"&daily=weather_code,temperature_2m_max,temperature_2m_min,sunrise,sunset"
+            f"&forecast_days={max(FORECAST_DAYS, 2)}"
+            f"&timezone=auto"
+        )
+        
+        response = self.cached_get('forecast', lat, lon, url, fetch_id=fetch_id, session=session, timeout=10)
+        response.raise_for_status()
+        print(f"[{fetch_id}] [Weather] Fetched from {url_without_query(response.url)}")
+        data = response.json()
+        
+        # A malformed response must not reach the UI as "nan°" (or "nan km/h", "inf hPa")
+        current = data.get('current') or {}
+        non_finite = {field: value for field, value in current.items()
+                      if isinstance(value, float) and not math.isfinite(value)}
+        if non_finite:
+            print(f"[{fetch_id}] [Weather] Non-finite {', '.join(non_finite)}, raw body: {response.text}")
+            raise ValueError(', '.join(f"{field} is {value}" for field, value in non_finite.items()))
+        return data
+    
+    def apply_weather(self, data, fetch_id):
+        """UI half of fetch_weather (main thread only)
+
+        A response the display can't use counts as a failed fetch: it neither
+        resets the failure count nor replaces the saved cache.
+        """
+        for field, expected, got in unit_mismatches(data):
+            print(f"[{fetch_id}] [Weather] Warning: requested {field} in {expected}, response says {got}")
+        if not self.update_weather_display(data):
+            self.weather_fetch_failed(ValueError("response could not be displayed"), fetch_id)
+            return
+        self.set_fetching('weather', False)
+        if self.weather_degraded:
+            print(f"[{fetch_id}] [Weather] Service reachable again after {self.weather_failures} failed fetches")
+        self.weather_failures = 0
+        self.weather_degraded = False
+        self.weather_live = True
+        self.save_weather_cache(data)
+    
+    def weather_fetch_failed(self, error, fetch_id):
+        self.set_fetching('weather', False)
+        self.weather_failures += 1
+        print(f"[{fetch_id}] Error fetching weather ({self.weather_failures} in a row): {error}")
+        self.update_health_line()
+        # Offline startup: show the last saved response if it is recent enough
+        if self.weather_updated_at is None:
+            self.load_weather_cache()
+        
+        # No live data yet: retry soon (slow network at boot), with a visible attempt counter if nothing is shown
+        if not self.weather_live and self.weather_failures < STARTUP_ATTEMPTS:
+            delay = STARTUP_RETRY_DELAY * 2 ** (self.weather_failures - 1)
+            print(f"[{fetch_id}] [Weather] Startup fetch failed, retrying in {delay}s")
+            if self.weather_updated_at is None:
+                self.canvas.itemconfig('temperature', text=f"--{temperature_suffix()}")
+                self.canvas.itemconfig('description', text=f"Connecting… (attempt {self.weather_failures + 1})")
+            self.root.after(delay * 1000, self.fetch_weather)
+            return
+        
+        # A one-off blip keeps the last data on screen; repeated failures say so
+        if self.weather_failures >= FETCH_FAILURE_THRESHOLD:
+            if not self.weather_degraded:
+                print(f"[{fetch_id}] [Weather] {self.weather_failures} failed fetches in a row, marking the service unreachable")
+            self.weather_degraded = True
+            self.canvas.itemconfig('description', text="Weather service unreachable")
+            self.update_staleness()
+        elif self.weather_updated_at is None:
             self.canvas.itemconfig('temperature', text="Error")
             self.canvas.itemconfig('description', text="Unable to fetch weather")
     
-    def update_weather_display(self, data):
-        """Update UI with weather data"""
+    def start_fetch_worker(self):
+        """Start the weather worker thread and the UI-side result poll (once)"""
+        if self._fetch_worker is not None:
+            return
+        self._fetch_worker = threading.Thread(target=self.run_fetch_worker, name='weather-fetch', daemon=True)
+        self._fetch_worker.start()
+        self._fetch_poll_after_id = self.root.after(FETCH_POLL_INTERVAL, self.poll_fetch_results)
+        print("[Weather] Background fetch worker started")
+    
+    def run_fetch_worker(self):
+        """Worker thread loop: performs requests, never touches the canvas"""
+        session = new_http_session()
+        while True:
+            job = self._fetch_jobs.get()
+            if job is None:
+                return
+            lat, lon, fetch_id, base = job
+            try:
+                self._fetch_results.put((True, self.request_weather(lat, lon, fetch_id, base, session), fetch_id))
+            except Exception as e:
+                self._fetch_results.put((False, e, fetch_id))
+    
+    def poll_fetch_results(self):
+        """Apply finished background fetches on the Tk thread"""
+        if not self.ui_alive('fetch poll'):
+            return
+        while True:
+            try:
+                ok, payload, fetch_id = self._fetch_results.get_nowait()
+            except queue.Empty:
+                break
+            self._weather_in_flight = False
+            try:
+                if ok:
+                    self.apply_weather(payload, fetch_id)
+                else:
+                    self.weather_fetch_failed(payload, fetch_id)
+            except Exception as e:
+                print(f"[{fetch_id}] Error applying weather: {e}")
+        self._fetch_poll_after_id = self.root.after(FETCH_POLL_INTERVAL, self.poll_fetch_results)
+    
+    def report_ui_error(self, exc_type, value, tb):
+        """Tk callback error hook: log it, and leave the main loop when errors keep coming"""
+        print(f"[Display] UI callback error: {value}")
+        traceback.print_exception(exc_type, value, tb)
+        now = time.monotonic()
+        self.ui_errors.append(now)
+        while now - self.ui_errors[0] > UI_ERROR_WINDOW:
+            self.ui_errors.popleft()
+        if len(self.ui_errors) >= UI_ERROR_BURST and not self.ui_failed:
+            print(f"[Display] {len(self.ui_errors)} UI errors in {UI_ERROR_WINDOW}s, recreating the window")
+            self.ui_failed = True
+            self.root.quit()
+    
+    def ui_alive(self, timer):
+        """False once the canvas is destroyed, so a timer stops instead of erroring every tick
+
+        Logged once per timer; the caller returns without rescheduling.
+        """
+        try:
+            if self.canvas.winfo_exists():
+                return True
+        except tk.TclError:
+            pass
+        if timer not in self._stopped_timers:
+            self._stopped_timers.add(timer)
+            print(f"[Display] Canvas is gone, stopping the {timer} timer")
+        return False
+    
+    def shutdown(self):
+        """Release what outlives the window (worker thread, GPIO pin, backlight level) before exit or a rebuild"""
+        self.stop_fetch_worker()
+        if self.alert_output is not None:
+            self.alert_output.close()
+        if self.set_backlight is not None:
+            try:
+                self.set_backlight(self._startup_brightness)  # don't leave the panel dimmed once the display stops
+            except OSError:
+                pass
+    
+    def stop_fetch_worker(self):
+        """Ask the worker to exit and wait briefly for an in-flight request"""
+        if self._fetch_worker is None:
+            return
+        self._fetch_jobs.put(None)
+        self._fetch_worker.join(timeout=FETCH_WORKER_JOIN_TIMEOUT)
+        if self._fetch_worker.is_alive():
+            print("[Weather] Fetch worker still busy at shutdown, abandoning it")
+        self._fetch_worker = None
+    
+    def save_weather_cache(self, data):
+        """Persist the last good weather response for offline startups"""
+        try:
+            path = cache_path(WEATHER_CACHE_FILE)
+            with open(path + '.tmp', 'w') as f:
+                json.dump({'saved_at': local_now().isoformat(), 'data': data}, f)
+            os.replace(path + '.tmp', path)
+        except Exception as e:
+            print(f"[Cache] Could not save weather cache: {e}")
+    
+    def load_weather_cache(self):
+        """Show the cached weather if younger than CACHE_MAX_AGE_MINUTES; True when shown"""
+        try:
+            with open(cache_path(WEATHER_CACHE_FILE)) as f:
+                cached = json.load(f)
+            saved_at = datetime.fromisoformat(cached['saved_at'])
+        except FileNotFoundError:
+            return False
+        except Exception as e:
+            print(f"[Cache] Could not read weather cache: {e}")
+            return False
+        
+        age_minutes = (local_now() - saved_at).total_seconds() / 60
+        if age_minutes > CACHE_MAX_AGE_MINUTES:
+            print(f"[Cache] Weather cache rejected: {int(age_minutes)} min old (max {CACHE_MAX_AGE_MINUTES})")
+            return False
+        
+        print(f"[Cache] Showing cached weather from {saved_at:%H:%M} ({int(age_minutes)} min old)")
+        return self.update_weather_display(cached['data'], saved_at=saved_at)
+    
+    def update_weather_display(self, data, saved_at=None):
+        """Update UI with weather data; False when the response could not be shown
+
+        saved_at marks a replay of the response cached then: staleness uses its
+        real age, and the temperature/pressure histories (stamped "now") are left alone.
+        """
         try:
             current = data['current']
             
-            # Temperature
-            temp = round(current['temperature_2m'])
-            self.canvas.itemconfig('temperature', text=f"{temp}°")
+            # Temperature (null in sparse-coverage areas: show a dash, keep the rest of the update)
+            unit = (data.get('current_units') or {}).get('temperature_2m', '°C')
+            temperature = current.get('temperature_2m')
+            apparent = current.get('apparent_temperature')
+            show_apparent = PRIMARY_TEMP == 'apparent' and apparent is not None
+            if temperature is None:
+                print("[Weather] Warning: temperature_2m is null, showing a placeholder")
+                self.canvas.itemconfig('temperature', text=f"—{temperature_suffix()}")
+                self.temperature_color = '#FFFFFF'
+                self.canvas.itemconfig('frost_badge', state='hidden')
+                self.alert_active = False
+                self.update_alert_output()
+            else:
+                primary = apparent if show_apparent else temperature
+                self.canvas.itemconfig('temperature', text=format_temperature(primary, unit))
+                
+                # Ice warning at or below 0°C / 32°F (always the measured temperature)
+                freezing = to_celsius(temperature, unit) <= 0
+                if TEMP_COLOR_SCALE:
+                    self.temperature_color = temperature_to_color(to_celsius(primary, unit))
+                self.canvas.itemconfig('frost_badge', state='normal' if freezing else 'hidden')
+                self.alert_active = freezing
+                self.update_alert_output()
+                
+                if SPARKLINE_POINTS > 0 and saved_at is None:
+                    self.temperature_history.append(temperature)
+                    self.draw_sparkline()
+            if show_apparent and temperature is not None:
+                self.canvas.itemconfig('secondary_temperature', state='normal',
+                                       text=f"Actual {format_temperature(temperature, unit)}")
+            else:
+                self.canvas.itemconfig('secondary_temperature', state='hidden')
+            self.position_frost_badge()
+            self.last_apparent_temp = None if apparent is None else to_celsius(apparent, unit)  # HEAT_TINT works in °C
 
             # Weather description
             weather_code = current.get('weather_code', 0)
-            description = WEATHER_CODES.get(weather_code, 'Unknown')
-            self.canvas.itemconfig('description', text=description)
+            description = weather_description(weather_code)
+            precip = self.current_hour_precipitation(data)
+            if CONDITION_SHOW_PRECIP and precip is not None and precip >= 0.1:
+                description = f"{description} · {format_number(precip, 1)}mm/h"
+            self.canvas.itemconfig('description', text=truncate_with_ellipsis(description))
             self.last_weather_code = weather_code
             self.update_background()
+            
+            self.update_details(data)
+            self.update_pressure(data, record=saved_at is None)
+            self.update_next_rain(data)
+            self.update_forecast_time(data)
+            
+            self.daily_forecast = self.parse_daily_forecast(data.get('daily') or {})
+            self.last_weather_data = data
+            self.draw_hourly()
+            self.update_summary()
+            self.sun_days = self.parse_sun_days(data.get('daily') or {})
+            self.update_sun_countdown()
+            self.weather_updated_at = saved_at or local_now()
+            self.update_snapshot(
+                temperature=None if temperature is None else to_celsius(temperature, unit),
+                weather_code=weather_code,
+                condition=description,
+                updated_at=self.weather_updated_at.isoformat(timespec='seconds'),
+            )
+            self.update_staleness()
+            return True
         except Exception as e:
             print(f"Error updating display: {e}")
+            return False
+    
+    def update_next_rain(self, data):
+        """Show when rain is next expected; hidden when the response has no hourly data"""
+        if not SHOW_NEXT_RAIN:
+            return
+        hourly = data.get('hourly') or {}
+        if not hourly.get('time'):
+            self.canvas.itemconfig('next_rain', state='hidden')
+            return
+        when = next_rain(data, local_now())
+        text = f"Rain expected ~{when:%H:%M}" if when else f"No rain next {NEXT_RAIN_HOURS}h"
+        self.canvas.itemconfig('next_rain', text=text, state='normal')
+    
+    def update_forecast_time(self, data):
+        """Show the conditions at FORECAST_TIME; hidden when the hourly rows don't reach it"""
+        if not FORECAST_TIME:
+            return
+        forecast = forecast_at(data, local_now(), FORECAST_TIME)
+        if forecast is None:
+            self.canvas.itemconfig('forecast_time', state='hidden')
+            return
+        when, code, temperature = forecast
+        unit = (data.get('hourly_units') or {}).get('temperature_2m', '°C')
+        text = f"{when:%H:%M}: {weather_description(code)}, {format_temperature(temperature, unit)}"
+        self.canvas.itemconfig('forecast_time', text=truncate_with_ellipsis(text), state='normal')
+    
+    def draw_hourly(self):
+        """Draw the SHOW_HOURLY strip from the last response; the current hour is labelled "Now" and boxed
+
+        Redrawn every minute, so the marker moves on at the top of the hour
+        without waiting for the next fetch.
+        """
+        self.canvas.delete('hourly')
+        if not SHOW_HOURLY or self.last_weather_data is None or self._hourly_box is None:
+            return
+        cells = hourly_strip(self.last_weather_data, local_now(), HOURLY_HOURS)
+        if not cells:
+            return
+        
+        unit = (self.last_weather_data.get('hourly_units') or {}).get('temperature_2m', '°C')
+        center_x, top, total_width = self._hourly_box
+        column = total_width / HOURLY_HOURS
+        state = 'hidden' if self.night_face else 'normal'
+        for i, (when, temperature, is_now) in enumerate(cells):
+            x = center_x - total_width / 2 + column * (i + 0.5)
+            self.canvas.create_text(
+                x, top, text="Now" if is_now or when is None else f"{when:%H:%M}",
+                font=('IBM Plex Mono', 14, 'bold italic'), fill='#FFFFFF',
+                anchor='n', state=state, tags=('hourly',)
+            )
+            self.canvas.create_text(
+                x, top + 22, text=format_temperature(temperature, unit),
+                font=('IBM Plex Mono', 20, 'bold italic'), fill='#FFFFFF',
+                anchor='n', state=state, tags=('hourly',)
+            )
+            if is_now:
+                self.canvas.create_rectangle(
+                    x - column / 2 + 4, top - 4, x + column / 2 - 4, top + 52,
+                    outline='#FFFFFF', width=2, state=state, tags=('hourly',)
+                )
+    
+    def update_pressure(self, data, record=True):
+        """Show pressure with its trend arrow (no arrow until there are two readings in the window)
+
+        record=False shows the value without adding it to the trend readings (cached replays).
+        """
+        if not SHOW_PRESSURE:
+            return
+        pressure = (data.get('current') or {}).get('pressure_msl')
+        if pressure is None:
+            self.canvas.itemconfig('pressure', state='hidden')
+            return
+        if record:
+            self.pressure_history.append((local_now(), pressure))
+        trend = pressure_trend(list(self.pressure_history))
+        text = f"{format_number(round(pressure))} hPa"
+        if trend:
+            text += f" {PRESSURE_TREND_ARROWS[trend]}"
+        self.canvas.itemconfig('pressure', text=text, state='normal')
+    
+    def update_details(self, data):
+        """Show wind (with notable gusts), humidity and the comfort label on one line
+        
+        Each part is left out when the response lacks it or, with
+        SHOW_ONLY_RELEVANT, when it is unremarkable; nothing left hides the line.
+        """
+        if not (SHOW_WIND or SHOW_HUMIDITY or SHOW_COMFORT):
+            return
+        current = data.get('current') or {}
+        parts = []
+        
+        speed = current.get('wind_speed_10m')
+        if SHOW_WIND and speed is not None:
+            unit = (data.get('current_units') or {}).get('wind_speed_10m', 'km/h').replace('mp/h', 'mph')
+            if not SHOW_ONLY_RELEVANT or wind_is_relevant(speed, unit):
+                parts.append(format_wind(speed, current.get('wind_gusts_10m'), unit))
+        
+        humidity = current.get('relative_humidity_2m')
+        if SHOW_HUMIDITY and humidity is not None:
+            if not SHOW_ONLY_RELEVANT or humidity_is_relevant(humidity):
+                parts.append(f"{format_number(round(humidity))}% RH")
+        
+        temperature = current.get('temperature_2m')
+        if SHOW_COMFORT and humidity is not None and temperature is not None:
+            unit = (data.get('current_units') or {}).get('temperature_2m', '°C')
+            comfort = comfort_level(to_celsius(temperature, unit), humidity)
+            if not SHOW_ONLY_RELEVANT or comfort != 'Comfortable':
+                parts.append(comfort)
+        
+        if parts:
+            self.canvas.itemconfig('details', text=" · ".join(parts), state='normal')
+        else:
+            self.canvas.itemconfig('details', state='hidden')
+    
+    def update_snapshot(self, **changes):
+        """Apply changed fields to the shared Snapshot (called by the fetch callbacks)"""
+        with self._snapshot_lock:
+            self._snapshot = replace(self._snapshot, time=local_now().isoformat(timespec='seconds'), **changes)
+    
+    def snapshot(self):
+        """The current Snapshot (immutable, safe to hand to other threads)"""
+        with self._snapshot_lock:
+            return self._snapshot
+    
+    def draw_sparkline(self):
+        """Draw the recent temperature trend under the condition (needs at least two readings)"""
+        self.canvas.delete('sparkline')
+        points = list(self.temperature_history)
+        if SPARKLINE_POINTS <= 0 or len(points) < 2 or self._sparkline_box is None:
+            return
+        
+        left, top = self._sparkline_box
+        width, height = SPARKLINE_SIZE
+        low, high = min(points), max(points)
+        span = (high - low) or 1  # Flat history draws a line through the middle
+        
+        coords = []
+        for i, value in enumerate(points):
+            x = left + width * i / (len(points) - 1)
+            y = top + height / 2 if high == low else top + height * (1 - (value - low) / span)
+            coords.extend((x, y))
+        state = 'hidden' if self.night_face else 'normal'
+        self.canvas.create_line(*coords, fill='#FFFFFF', width=2, smooth=True, state=state, tags=('sparkline',))
+    
+    def position_frost_badge(self):
+        """Pin the frost badge to the top-left of the temperature text, the secondary one to its bottom-left"""
+        bbox = self.canvas.bbox('temperature')
+        if bbox:
+            self.canvas.coords('frost_badge', bbox[0] - 8, bbox[1] + 16)
+            self.canvas.coords('secondary_temperature', bbox[0] - 8, bbox[3] - 20)
+    
+    def current_hour_precipitation(self, data):
+        """Precipitation (mm) of the hourly row containing the current time, or None"""
+        amounts = (data.get('hourly') or {}).get('precipitation') or []
+        i = current_hour_index(data, local_now())
+        if i is None or i >= len(amounts):
+            return None
+        return amounts[i]
+    
+    def parse_sun_days(self, daily):
+        """(sunrise, sunset) per day from the daily arrays; None entries mark polar days"""
+        sunrises = daily.get('sunrise') or []
+        sunsets = daily.get('sunset') or []
+        return [
+            (parse_local_time(sunrise), parse_local_time(sunset))
+            for sunrise, sunset in zip(sunrises, sunsets)
+        ]
+    
+    def update_sun_countdown(self):
+        """Refresh "Sunset in 2h 14m"; hidden without sun times (polar regions, no data yet)"""
+        if not SHOW_SUN_COUNTDOWN:
+            return
+        now = local_now()
+        event = next_sun_event(now, self.sun_days)
+        if event is None:
+            self.canvas.itemconfig('sun_countdown', state='hidden')
+            return
+        name, when = event
+        text = f"{name} in {format_countdown((when - now).total_seconds())}"
+        self.canvas.itemconfig('sun_countdown', text=text, state='normal')
+    
+    def parse_daily_forecast(self, daily):
+        """Turn Open-Meteo's column arrays into one dict per day (at most FORECAST_DAYS)"""
+        dates = daily.get('time') or []
+        codes = daily.get('weather_code') or []
+        highs = daily.get('temperature_2m_max') or []
+        lows = daily.get('temperature_2m_min') or []
+        
+        days = []
+        for i, date in enumerate(dates[:FORECAST_DAYS]):
+            days.append({
+                'date': date,
+                'weather_code': codes[i] if i < len(codes) else None,
+                'temp_max': highs[i] if i < len(highs) else None,
+                'temp_min': lows[i] if i < len(lows) else None,
+            })
+        return days
     
     def update_datetime(self):
-        """Update date and time display"""
-        now = datetime.now()
+        """Update date and time display (also runs when the clock is hidden, to keep the gradient phase current)"""
+        now = local_now()
         formatted = now.strftime("%H:%M")
         self.canvas.itemconfig('datetime', text=formatted)
         self.update_background()
+        self.draw_hourly()
+        self.update_night_face()
+        self.update_brightness()
+        self.update_sun_countdown()
+        self.update_staleness()
+    
+    def update_staleness(self):
+        """Dim the weather and AQI sections independently once their data gets old"""
+        now = local_now()
+        weather_stale = (self.weather_updated_at is not None and
+                         (now - self.weather_updated_at).total_seconds() > WEATHER_STALE_MINUTES * 60)
+        aqi_stale = (self.aqi_updated_at is not None and
+                     (now - self.aqi_updated_at).total_seconds() > AQI_STALE_MINUTES * 60)
+        
+        if weather_stale != self.weather_stale:
+            print(f"[Stale] Weather data {'stale' if weather_stale else 'fresh'} (updated {self.weather_updated_at:%H:%M})")
+        if aqi_stale != self.aqi_stale:
+            print(f"[Stale] AQI data {'stale' if aqi_stale else 'fresh'} (updated {self.aqi_updated_at:%H:%M})")
+        self.weather_stale = weather_stale
+        self.aqi_stale = aqi_stale
+        
+        weather_dimmed = weather_stale or self.weather_degraded
+        weather_fill = STALE_COLOR if weather_dimmed else '#FFFFFF'
+        self.canvas.itemconfig('temperature', fill=STALE_COLOR if weather_dimmed else self.temperature_color)
+        self.canvas.itemconfig('description', fill=weather_fill)
+        self.canvas.itemconfig('secondary_temperature', fill=weather_fill)
+        self.canvas.itemconfig('aqi_status', fill=STALE_COLOR if aqi_stale else '#FFFFFF')
+        self.update_health_line()
+    
+    def update_health_line(self):
+        """Refresh the bottom status line from the fetch outcome state
+
+        With SHOW_HEALTH it always shows the detailed line. Otherwise it only
+        appears while a source is broken, naming which one ("Weather OK · AQI error").
+        """
+        if not SHOW_HEALTH:
+            # Startup retries and single failed fetches (last data kept) don't count as broken
+            weather_broken = self.weather_degraded or (self.weather_updated_at is None
+                                                       and self.weather_failures >= STARTUP_ATTEMPTS)
+            aqi_broken = AIR_QUALITY_ENABLED and self.aqi_failed
+            # A fallback provider hides a rejected key from the status line, so it surfaces here
+            key_rejected = AIR_QUALITY_ENABLED and self.airly_key_invalid
+            if not (weather_broken or aqi_broken or key_rejected):
+                self.canvas.itemconfig('health', state='hidden')
+                return
+            parts = [f"Weather {'error' if weather_broken else 'OK'}"]
+            if AIR_QUALITY_ENABLED:
+                parts.append(f"AQI {'error' if aqi_broken else 'OK'}")
+            if key_rejected:
+                parts.append("Airly API key rejected")
+            self.canvas.itemconfig('health', text=" · ".join(parts), state='normal')
+            return
+        
+        def outcome(failed, stale, updated_at):
+            if failed:
+                status = 'fail'
+            elif stale:
+                status = 'stale'
+            else:
+                status = 'ok' if updated_at else '--'
+            return f"{status} {updated_at:%H:%M}" if updated_at else status
+        
+        parts = [f"WX:{outcome(self.weather_failures > 0, self.weather_stale, self.weather_updated_at)}"]
+        if AIR_QUALITY_ENABLED:
+            aqi = f"AQI:{outcome(self.aqi_failed, self.aqi_stale, self.aqi_updated_at)}"
+            if self.aqi_provider:
+                aqi += f" ({self.aqi_provider})"
+            parts.append(aqi)
+            if self.airly_key_invalid:
+                parts.append("Airly key rejected")
+        self.canvas.itemconfig('health', text=" · ".join(parts))
     
     def schedule_weather_update(self):
         """Schedule weather updates using Tkinter's after() (more efficient than threads)"""
+        if not self.ui_alive('weather'):
+            return
         try:
-            self.fetch_weather()
+            if self.is_quiet_time():
+                print("[Quiet] Skipping weather fetch (quiet hours)")
+                self._last_weather_attempt = time.monotonic()  # timer is alive, keep the watchdog calm
+            else:
+                self.fetch_weather()
+                self.update_pollen()
         except Exception as e:
             print(f"Error in weather update: {e}")
         # Schedule next update
@@ -867,33 +3783,148 @@ class WeatherDisplay:
     
     def schedule_time_update(self):
         """Schedule time updates synced to the start of each minute"""
+        if not self.ui_alive('time'):
+            return
         try:
             self.update_datetime()
+            self.check_resume_gap()
+            self.check_fetch_watchdog()
+            if not self.demo:
+                self.check_quiet_hours()
         except Exception as e:
             print(f"Error in time update: {e}")
         
         # Calculate milliseconds until the next minute starts
-        now = datetime.now()
+        now = local_now()
         seconds_until_next_minute = 60 - now.second
         ms_until_next_minute = (seconds_until_next_minute * 1000) - (now.microsecond // 1000)
         
         # Schedule next update at the start of the next minute
         self._time_after_id = self.root.after(ms_until_next_minute, self.schedule_time_update)
     
+    def refetch_all(self):
+        """Fetch every data source now, outside the regular schedules (not during quiet hours)"""
+        if self.is_quiet_time():
+            print("[Quiet] Skipping refetch (quiet hours)")
+            return
+        self.fetch_weather()
+        self.update_pollen()
+        self.fetch_air_quality()
+        self.fetch_transport()
+    
+    def check_resume_gap(self):
+        """Refetch when the minute tick arrives far too late (system suspend / display sleep)"""
+        now = time.time()
+        previous, self._last_clock_tick = self._last_clock_tick, now
+        if previous is None or self.demo:
+            return
+        gap = now - previous
+        if gap > RESUME_GAP_THRESHOLD:
+            print(f"[Resume] Clock ticks were {int(gap)}s apart, refetching data")
+            if not self.is_quiet_time():
+                self.refetch_all()
+    
+    def is_quiet_time(self):
+        return in_quiet_hours(local_now(), QUIET_HOURS)
+    
+    def is_daylight(self, now):
+        """True between today's sunrise and sunset (fetched, or estimated from the latitude)"""
+        sun_hours = self.sun_hours_for(now)
+        if sun_hours is None:
+            return False
+        sunrise, sunset = sun_hours
+        return sunrise <= now.hour + now.minute / 60 < sunset
+    
+    def check_quiet_hours(self):
+        """Refetch once when quiet hours end so the morning data is fresh"""
+        quiet = self.is_quiet_time()
+        if quiet and not self._in_quiet_hours:
+            print("[Quiet] Quiet hours started, pausing network fetches")
+        elif self._in_quiet_hours and not quiet:
+            print("[Quiet] Quiet hours ended, refetching data")
+            if self._start_deferred:
+                self.start_fetching()
+            else:
+                self.refetch_all()
+        self._in_quiet_hours = quiet
+    
+    def check_fetch_watchdog(self):
+        """Restart fetch timers that have gone silent (runs from the minute clock tick)"""
+        now = time.monotonic()
+        
+        if self._last_weather_attempt is not None:
+            silent = now - self._last_weather_attempt
+            if silent > WATCHDOG_FACTOR * REFRESH_INTERVAL:
+                print(f"[Watchdog] ERROR: no weather fetch for {int(silent)}s, forcing one now")
+                if self._weather_after_id:
+                    self.root.after_cancel(self._weather_after_id)
+                self.schedule_weather_update()
+        
+        if self._last_aqi_tick is not None:
+            silent = now - self._last_aqi_tick
+            if silent > WATCHDOG_FACTOR * 60:
+                print(f"[Watchdog] ERROR: AQI scheduler silent for {int(silent)}s, restarting it")
+                if self._aqi_after_id:
+                    self.root.after_cancel(self._aqi_after_id)
+                self.schedule_aqi_update()
+    
+    def schedule_connectivity_check(self):
+        """Probe connectivity every minute and refetch on an offline -> online transition
+
+        The probe itself is network traffic, so it pauses during quiet hours;
+        their end refetches everything anyway.
+        """
+        if not self.ui_alive('connectivity'):
+            return
+        try:
+            if not self.is_quiet_time():
+                self.probe_connectivity()
+        except Exception as e:
+            print(f"Error in connectivity check: {e}")
+        self._connectivity_after_id = self.root.after(
+            CONNECTIVITY_CHECK_INTERVAL * 1000, self.schedule_connectivity_check
+        )
+    
+    def probe_connectivity(self):
+        """One connectivity probe; refetches (debounced) when the connection came back"""
+        online = is_online()
+        if online and not self._was_online:
+            now = time.monotonic()
+            if (self._last_reconnect_refetch is None
+                    or now - self._last_reconnect_refetch >= RECONNECT_DEBOUNCE):
+                print("[Network] Connection restored, refetching data")
+                self._last_reconnect_refetch = now
+                self.refetch_all()
+            else:
+                print("[Network] Connection restored, refetch skipped (debounced)")
+        elif not online and self._was_online:
+            print("[Network] Connection lost")
+        self._was_online = online
+    
     def schedule_aqi_update(self):
         """Schedule air quality updates using Tkinter's after() - only at 6am, 3pm, and 8pm"""
-        scheduled_hours = [6, 15, 20]
-        now = datetime.now()
-        current_hour = now.hour
+        if not self.ui_alive('air quality'):
+            return
+        self._last_aqi_tick = time.monotonic()
+        now = local_now()
+        # With an offset of 23 minutes, the 06:00 slot runs from 06:23 to 07:22
+        current_hour = (now - timedelta(minutes=self.aqi_fetch_offset)).hour
         
         # Check if we're at a scheduled hour and haven't fetched yet this hour
-        if current_hour in scheduled_hours and self.last_aqi_fetch_hour != current_hour:
-            try:
-                print(f"[AQI] Scheduled fetch at {now.strftime('%H:%M')}")
-                self.fetch_air_quality()
+        if should_fetch_aqi(current_hour, self.last_aqi_fetch_hour, AQI_SCHEDULED_HOURS):
+            if self.is_quiet_time():
+                print(f"[Quiet] Skipping scheduled AQI fetch at {now.strftime('%H:%M')} (quiet hours)")
                 self.last_aqi_fetch_hour = current_hour
-            except Exception as e:
-                print(f"Error in air quality update: {e}")
+            elif AQI_DAYTIME_ONLY and not self.is_daylight(now):
+                print(f"[AQI] Skipping scheduled fetch at {now.strftime('%H:%M')} (outside daylight)")
+                self.last_aqi_fetch_hour = current_hour
+            else:
+                try:
+                    print(f"[AQI] Scheduled fetch at {now.strftime('%H:%M')}")
+                    self.fetch_air_quality()
+                    self.last_aqi_fetch_hour = current_hour
+                except Exception as e:
+                    print(f"Error in air quality update: {e}")
         
         # Check again in 1 minute
         self._aqi_after_id = self.root.after(60 * 1000, self.schedule_aqi_update)
@@ -904,12 +3935,14 @@ class WeatherDisplay:
         Makes a single API call for S-Bahn departures, then groups by line name
         to display up to 2 different lines in separate rows.
         """
+        if not SHOW_TRANSPORT:
+            return
         if self.debug_enabled:
             print("[Transport] Fetching transport data...")
         
         try:
             # Single API call for all S-Bahn departures
-            response = requests.get(build_transport_url(), timeout=15)
+            response = http_get(build_transport_url(), timeout=15)
             data = response.json()
             departures = data.get('departures', [])
             
@@ -980,7 +4013,7 @@ class WeatherDisplay:
         
         # Calculate minutes for up to 3 valid departures
         minutes_list = []
-        now = datetime.now()
+        now = local_now()
         
         for dep in departures:  # Iterate through all departures
             if len(minutes_list) >= 3:
@@ -1034,8 +4067,13 @@ class WeatherDisplay:
     
     def schedule_transport_update(self):
         """Schedule transport API updates using Tkinter's after()"""
+        if not self.ui_alive('transport'):
+            return
         try:
-            self.fetch_transport()
+            if self.is_quiet_time():
+                print("[Quiet] Skipping transport fetch (quiet hours)")
+            else:
+                self.fetch_transport()
         except Exception as e:
             print(f"Error in transport update: {e}")
         # Schedule next API update
@@ -1053,6 +4091,8 @@ class WeatherDisplay:
     
     def schedule_transport_display_update(self):
         """Schedule transport display updates (countdown refresh) using Tkinter's after()"""
+        if not self.ui_alive('transport display'):
+            return
         try:
             self.update_transport_display()
         except Exception as e:
@@ -1060,35 +4100,176 @@ class WeatherDisplay:
         # Schedule next display update
         self._transport_display_after_id = self.root.after(TRANSPORT_DISPLAY_INTERVAL * 1000, self.schedule_transport_display_update)
     
+    def save_screenshot(self, path, then_quit=False):
+        """Write what is on screen to a PNG (captures the window area from X11, so it must be visible)"""
+        try:
+            from PIL import ImageGrab
+            
+            self.root.update_idletasks()
+            x, y = self.root.winfo_rootx(), self.root.winfo_rooty()
+            image = ImageGrab.grab(bbox=(x, y, x + self.root.winfo_width(), y + self.root.winfo_height()))
+            image.save(path, 'PNG')
+            print(f"[Screenshot] Saved {image.width}x{image.height} to {path}")
+        except Exception as e:
+            print(f"[Screenshot] Could not capture the display: {e}")
+        if then_quit:
+            self.root.quit()
+    
+    def start_systemd_notify(self):
+        """Tell systemd we're up once the event loop runs, and start the watchdog pings"""
+        self.root.after_idle(self.notify_ready)
+        interval = sd_watchdog_interval()
+        if interval:
+            print(f"[systemd] Watchdog enabled, pinging every {interval:.0f}s")
+            self.schedule_sd_watchdog(interval)
+    
+    def notify_ready(self):
+        if sd_notify('READY=1'):
+            print("[systemd] Sent READY=1")
+    
+    def schedule_sd_watchdog(self, interval):
+        """Ping the systemd watchdog; a stuck Tk loop stops the pings and systemd restarts us"""
+        if not self.ui_alive('systemd watchdog'):
+            return
+        sd_notify('WATCHDOG=1')
+        self._sd_watchdog_after_id = self.root.after(int(interval * 1000), lambda: self.schedule_sd_watchdog(interval))
+    
     def start_updates(self):
         """Start all update schedules using Tkinter's after() (more efficient than threads on Pi Zero)"""
-        # Initial data fetch
+        self.start_systemd_notify()
+        
+        # The indoor sensor is local hardware: no jitter, and it keeps reading in demo mode
+        if self.indoor_sensor:
+            self.schedule_indoor_update()
+
+        if self.demo:
+            # Offline: simulated data only, the clock keeps real time
+            print("[Demo] Demo mode - no network requests")
+            self.run_demo_cycle()
+            self.schedule_time_update()
+            return
+        
+        self.update_datetime()
+        
+        # Sync time updates to the start of the next minute
+        now = local_now()
+        seconds_until_next_minute = 60 - now.second
+        ms_until_next_minute = (seconds_until_next_minute * 1000) - (now.microsecond // 1000)
+        self._time_after_id = self.root.after(ms_until_next_minute, self.schedule_time_update)
+        
+        self.canvas.itemconfig('description', text="Connecting…")
+        
+        # Network fetches start after the jitter; the periodic schedules below are
+        # relative to that, so their phase is randomized too
+        jitter = random.uniform(0, FETCH_JITTER)
+        print(f"[Network] Starting fetches in {jitter:.1f}s (FETCH_JITTER={FETCH_JITTER})")
+        if FETCH_SPREAD:
+            print(f"[Network] Fetch phase: weather +{self.weather_fetch_offset:.0f}s per cycle, "
+                  f"air quality at :{self.aqi_fetch_offset:02d} of each scheduled hour")
+        self.root.after(int(jitter * 1000), self.start_fetching)
+    
+    def start_fetching(self):
+        """Initial fetches plus the periodic fetch schedules (after the startup jitter)
+
+        During quiet hours nothing is fetched (geocoding included); the last
+        saved weather is shown and check_quiet_hours starts again at their end.
+        """
+        if self.is_quiet_time():
+            print("[Quiet] Quiet hours at startup, first fetches wait until they end")
+            self._start_deferred = True
+            self._in_quiet_hours = True  # so the end is noticed even if it comes before the next clock tick
+            if not self.load_weather_cache():
+                self.canvas.itemconfig('description', text="Paused (quiet hours)")
+            return
+        self._start_deferred = False
         self.get_coordinates_from_city()
+        if GPSD_ADDR:
+            self.update_location_from_gps()
+            self._gps_after_id = self.root.after(GPS_REFRESH_INTERVAL * 1000, self.schedule_gps_update)
+        if self.location_name is None:
+            self.resolve_location_name()
+        self._fetched_location = (self.latitude, self.longitude)
         self.fetch_weather()
+        self.update_pollen()
         self.fetch_air_quality()
         self.fetch_transport()
-        self.update_datetime()
         
         # Schedule periodic updates using after() instead of threads
         # This is more efficient on weak hardware as it avoids thread overhead
         # and doesn't require thread-safe UI updates
-        self._weather_after_id = self.root.after(REFRESH_INTERVAL * 1000, self.schedule_weather_update)
-        self._aqi_after_id = self.root.after(60 * 1000, self.schedule_aqi_update)
+        # The offset shifts the phase of every later cycle, not just the first
+        self._weather_after_id = self.root.after(
+            int((REFRESH_INTERVAL + self.weather_fetch_offset) * 1000), self.schedule_weather_update
+        )
+        if AIR_QUALITY_ENABLED:
+            self._aqi_after_id = self.root.after(60 * 1000, self.schedule_aqi_update)
         
-        # Sync time updates to the start of the next minute
-        now = datetime.now()
-        seconds_until_next_minute = 60 - now.second
-        ms_until_next_minute = (seconds_until_next_minute * 1000) - (now.microsecond // 1000)
-        self._time_after_id = self.root.after(ms_until_next_minute, self.schedule_time_update)
+        if SHOW_TRANSPORT:
+            self._transport_after_id = self.root.after(TRANSPORT_REFRESH_INTERVAL * 1000, self.schedule_transport_update)
+            self._transport_display_after_id = self.root.after(TRANSPORT_DISPLAY_INTERVAL * 1000, self.schedule_transport_display_update)
         
-        self._transport_after_id = self.root.after(TRANSPORT_REFRESH_INTERVAL * 1000, self.schedule_transport_update)
-        self._transport_display_after_id = self.root.after(TRANSPORT_DISPLAY_INTERVAL * 1000, self.schedule_transport_display_update)
+        if RECONNECT_REFETCH:
+            self._connectivity_after_id = self.root.after(
+                CONNECTIVITY_CHECK_INTERVAL * 1000, self.schedule_connectivity_check
+            )
 
 
 def main():
-    root = tk.Tk()
-    app = WeatherDisplay(root)
-    root.mainloop()
+    parser = argparse.ArgumentParser(description="Pi Weather Display")
+    parser.add_argument('--demo', action='store_true',
+                        help="cycle through simulated weather and time phases without fetching")
+    parser.add_argument('--screenshot', metavar='PATH',
+                        help="save the display to a PNG once the first data is in, then exit")
+    parser.add_argument('--screenshot-delay', type=float, metavar='SECONDS',
+                        help=f"wait this long before the screenshot (default: FETCH_JITTER + 15 = {FETCH_JITTER + 15})")
+    args = parser.parse_args()
+    
+    restarts = 0
+    while True:
+        try:
+            root = tk.Tk()
+        except tk.TclError as e:
+            # Headless first boot / HDMI not connected yet: say so instead of a traceback
+            print(f"[Display] No display available ({e}). Is DISPLAY set and the screen connected?")
+            raise SystemExit(1)
+        
+        try:
+            ui_failed = run_display(root, args)
+        except tk.TclError as e:
+            print(f"[Display] UI error: {e}")
+            ui_failed = True
+            try:
+                root.destroy()
+            except tk.TclError:
+                pass
+        if not ui_failed:
+            return
+        
+        restarts += 1
+        if restarts > UI_RECOVERY_ATTEMPTS:
+            print(f"[Display] Giving up after {UI_RECOVERY_ATTEMPTS} window restarts")
+            raise SystemExit(1)
+        print(f"[Display] Recreating the window in {UI_RECOVERY_DELAY}s (restart {restarts}/{UI_RECOVERY_ATTEMPTS})")
+        time.sleep(UI_RECOVERY_DELAY)
+
+
+def run_display(root, args):
+    """Run one window until it closes; True when it closed because of repeated UI errors"""
+    app = None
+    try:
+        app = WeatherDisplay(root, demo=args.demo)
+        if args.screenshot:
+            delay = args.screenshot_delay if args.screenshot_delay is not None else FETCH_JITTER + 15
+            root.after(int(delay * 1000), lambda: app.save_screenshot(args.screenshot, then_quit=True))
+        root.mainloop()
+    finally:
+        if app is not None:
+            app.shutdown()
+        try:
+            root.destroy()
+        except tk.TclError:
+            pass  # already gone with the display connection
+    return app.ui_failed
 
 
 if __name__ == '__main__':